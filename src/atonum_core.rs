@@ -0,0 +1,453 @@
+use crate::numtoa_error::AToNumError;
+use crate::numtoa_radix::Radix;
+
+// How arithmetic overflow is handled while accumulating digits.
+#[derive(Clone, Copy)]
+enum Overflow {
+    Checked,
+    Saturating,
+    Wrapping,
+}
+
+// Decodes an ASCII digit of any case into its value, which will be at least 36 for non-digits.
+const fn digit_value(byte: u8) -> u32 {
+    match byte {
+        b'0'..=b'9' => (byte - b'0') as u32,
+        b'a'..=b'z' => (byte - b'a') as u32 + 10,
+        b'A'..=b'Z' => (byte - b'A') as u32 + 10,
+        _ => u32::MAX,
+    }
+}
+
+// Splits an optional leading `+` or `-` from the digits, returning whether the number is negative.
+const fn split_sign(string: &[u8]) -> (bool, usize) {
+    match string.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    }
+}
+
+macro_rules! impl_unsigned_atonum_for {
+    (
+        $type_name:ty,
+        $parse_function_name:ident,
+        $core_function_name:ident,
+        $saturating_function_name:ident,
+        $wrapping_function_name:ident
+    ) => {
+        // Accumulates the digits of `string`, starting from `start`, into an unsigned integer.
+        const fn $parse_function_name(
+            string: &[u8],
+            start: usize,
+            radix: Radix,
+            overflow: Overflow,
+        ) -> Result<$type_name, AToNumError> {
+            if start == string.len() {
+                return Err(AToNumError::Empty);
+            }
+
+            let base = radix.get() as $type_name;
+            let mut saturated = false;
+            let mut value: $type_name = 0;
+            let mut index = start;
+            while index < string.len() {
+                let digit = digit_value(string[index]);
+                if digit >= radix.get() {
+                    return Err(AToNumError::InvalidDigit { index });
+                }
+
+                if matches!(overflow, Overflow::Wrapping) {
+                    value = value.wrapping_mul(base).wrapping_add(digit as $type_name);
+                } else if !saturated {
+                    match value.checked_mul(base) {
+                        Some(shifted) => match shifted.checked_add(digit as $type_name) {
+                            Some(sum) => value = sum,
+                            None => saturated = true,
+                        },
+                        None => saturated = true,
+                    }
+
+                    if saturated {
+                        if matches!(overflow, Overflow::Checked) {
+                            return Err(AToNumError::Overflow);
+                        }
+                        value = <$type_name>::MAX;
+                    }
+                }
+
+                index += 1;
+            }
+
+            Ok(value)
+        }
+
+        #[doc = concat!("Parses an [", stringify!($type_name), "] from its ASCII representation in the given radix, accepting digits of either case and an optional leading `+`.")]
+        pub const fn $core_function_name(
+            string: &[u8],
+            radix: Radix,
+        ) -> Result<$type_name, AToNumError> {
+            match split_sign(string) {
+                (false, start) => $parse_function_name(string, start, radix, Overflow::Checked),
+                (true, _) => Err(AToNumError::InvalidDigit { index: 0 }),
+            }
+        }
+
+        #[doc = concat!("Parses an [", stringify!($type_name), "] like [", stringify!($core_function_name), "], but values which are too large saturate at the numeric bounds instead of failing.")]
+        pub const fn $saturating_function_name(
+            string: &[u8],
+            radix: Radix,
+        ) -> Result<$type_name, AToNumError> {
+            match split_sign(string) {
+                (false, start) => $parse_function_name(string, start, radix, Overflow::Saturating),
+                (true, _) => Err(AToNumError::InvalidDigit { index: 0 }),
+            }
+        }
+
+        #[doc = concat!("Parses an [", stringify!($type_name), "] like [", stringify!($core_function_name), "], but values which are too large wrap around at the numeric bounds instead of failing.")]
+        pub const fn $wrapping_function_name(
+            string: &[u8],
+            radix: Radix,
+        ) -> Result<$type_name, AToNumError> {
+            match split_sign(string) {
+                (false, start) => $parse_function_name(string, start, radix, Overflow::Wrapping),
+                (true, _) => Err(AToNumError::InvalidDigit { index: 0 }),
+            }
+        }
+    };
+}
+
+macro_rules! impl_signed_atonum_for {
+    (
+        $type_name:ty,
+        $unsigned_type_name:ty,
+        $parse_function_name:ident,
+        $unsigned_parse_function_name:ident,
+        $core_function_name:ident,
+        $saturating_function_name:ident,
+        $wrapping_function_name:ident
+    ) => {
+        // Parses the magnitude as an unsigned integer, then applies the sign and checks the bounds.
+        const fn $parse_function_name(
+            string: &[u8],
+            radix: Radix,
+            overflow: Overflow,
+        ) -> Result<$type_name, AToNumError> {
+            let (negative, start) = split_sign(string);
+            let magnitude = match $unsigned_parse_function_name(string, start, radix, overflow) {
+                Ok(magnitude) => magnitude,
+                Err(why) => return Err(why),
+            };
+
+            if matches!(overflow, Overflow::Wrapping) {
+                let value = magnitude as $type_name;
+                return Ok(if negative { value.wrapping_neg() } else { value });
+            }
+
+            let limit = if negative {
+                <$type_name>::MIN.unsigned_abs()
+            } else {
+                <$type_name>::MAX as $unsigned_type_name
+            };
+
+            if magnitude <= limit {
+                // Negating the magnitude of the minimum value wraps back around to itself.
+                let value = magnitude as $type_name;
+                Ok(if negative { value.wrapping_neg() } else { value })
+            } else if matches!(overflow, Overflow::Saturating) {
+                Ok(if negative {
+                    <$type_name>::MIN
+                } else {
+                    <$type_name>::MAX
+                })
+            } else {
+                Err(AToNumError::Overflow)
+            }
+        }
+
+        #[doc = concat!("Parses an [", stringify!($type_name), "] from its ASCII representation in the given radix, accepting digits of either case and an optional leading `+` or `-`.")]
+        pub const fn $core_function_name(
+            string: &[u8],
+            radix: Radix,
+        ) -> Result<$type_name, AToNumError> {
+            $parse_function_name(string, radix, Overflow::Checked)
+        }
+
+        #[doc = concat!("Parses an [", stringify!($type_name), "] like [", stringify!($core_function_name), "], but values which are out of range saturate at the numeric bounds instead of failing.")]
+        pub const fn $saturating_function_name(
+            string: &[u8],
+            radix: Radix,
+        ) -> Result<$type_name, AToNumError> {
+            $parse_function_name(string, radix, Overflow::Saturating)
+        }
+
+        #[doc = concat!("Parses an [", stringify!($type_name), "] like [", stringify!($core_function_name), "], but values which are out of range wrap around at the numeric bounds instead of failing.")]
+        pub const fn $wrapping_function_name(
+            string: &[u8],
+            radix: Radix,
+        ) -> Result<$type_name, AToNumError> {
+            $parse_function_name(string, radix, Overflow::Wrapping)
+        }
+    };
+}
+
+impl_unsigned_atonum_for!(
+    u8,
+    parse_u8,
+    atonum_u8,
+    atonum_u8_saturating,
+    atonum_u8_wrapping
+);
+impl_unsigned_atonum_for!(
+    u16,
+    parse_u16,
+    atonum_u16,
+    atonum_u16_saturating,
+    atonum_u16_wrapping
+);
+impl_unsigned_atonum_for!(
+    u32,
+    parse_u32,
+    atonum_u32,
+    atonum_u32_saturating,
+    atonum_u32_wrapping
+);
+impl_unsigned_atonum_for!(
+    u64,
+    parse_u64,
+    atonum_u64,
+    atonum_u64_saturating,
+    atonum_u64_wrapping
+);
+#[cfg(feature = "u128")]
+impl_unsigned_atonum_for!(
+    u128,
+    parse_u128,
+    atonum_u128,
+    atonum_u128_saturating,
+    atonum_u128_wrapping
+);
+impl_unsigned_atonum_for!(
+    usize,
+    parse_usize,
+    atonum_usize,
+    atonum_usize_saturating,
+    atonum_usize_wrapping
+);
+impl_signed_atonum_for!(
+    i8,
+    u8,
+    parse_i8,
+    parse_u8,
+    atonum_i8,
+    atonum_i8_saturating,
+    atonum_i8_wrapping
+);
+impl_signed_atonum_for!(
+    i16,
+    u16,
+    parse_i16,
+    parse_u16,
+    atonum_i16,
+    atonum_i16_saturating,
+    atonum_i16_wrapping
+);
+impl_signed_atonum_for!(
+    i32,
+    u32,
+    parse_i32,
+    parse_u32,
+    atonum_i32,
+    atonum_i32_saturating,
+    atonum_i32_wrapping
+);
+impl_signed_atonum_for!(
+    i64,
+    u64,
+    parse_i64,
+    parse_u64,
+    atonum_i64,
+    atonum_i64_saturating,
+    atonum_i64_wrapping
+);
+#[cfg(feature = "u128")]
+impl_signed_atonum_for!(
+    i128,
+    u128,
+    parse_i128,
+    parse_u128,
+    atonum_i128,
+    atonum_i128_saturating,
+    atonum_i128_wrapping
+);
+impl_signed_atonum_for!(
+    isize,
+    usize,
+    parse_isize,
+    parse_usize,
+    atonum_isize,
+    atonum_isize_saturating,
+    atonum_isize_wrapping
+);
+
+#[cfg(test)]
+mod atonum_core_test {
+    use super::*;
+    #[cfg(feature = "u128")]
+    use crate::numtoa_alphabet::Alphabet;
+    use crate::numtoa_core::*;
+
+    #[test]
+    fn sanity() {
+        assert_eq!(Ok(256123), atonum_i32(b"256123", Radix::DEC));
+        assert_eq!(Ok(-256123), atonum_i32(b"-256123", Radix::DEC));
+        assert_eq!(Ok(256123), atonum_i32(b"+256123", Radix::DEC));
+        assert_eq!(Ok(0x3E87B), atonum_u32(b"3E87B", Radix::HEX));
+        assert_eq!(Ok(0x3E87B), atonum_u32(b"3e87b", Radix::HEX));
+        assert_eq!(Ok(35), atonum_u8(b"z", Radix::MAX));
+        assert_eq!(Ok(0), atonum_u8(b"0000", Radix::BIN));
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn bounds() {
+        assert_eq!(Ok(u8::MAX), atonum_u8(b"255", Radix::DEC));
+        assert_eq!(Ok(i8::MIN), atonum_i8(b"-128", Radix::DEC));
+        assert_eq!(Ok(i8::MAX), atonum_i8(b"127", Radix::DEC));
+        assert_eq!(
+            Ok(i128::MIN),
+            atonum_i128(b"-170141183460469231731687303715884105728", Radix::DEC)
+        );
+        assert_eq!(
+            Ok(u128::MAX),
+            atonum_u128(b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", Radix::HEX)
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Err(AToNumError::Empty), atonum_u32(b"", Radix::DEC));
+        assert_eq!(Err(AToNumError::Empty), atonum_u32(b"+", Radix::DEC));
+        assert_eq!(Err(AToNumError::Empty), atonum_i32(b"-", Radix::DEC));
+        assert_eq!(
+            Err(AToNumError::Empty),
+            atonum_i32_saturating(b"", Radix::DEC)
+        );
+        assert_eq!(
+            Err(AToNumError::Empty),
+            atonum_i32_wrapping(b"+", Radix::DEC)
+        );
+    }
+
+    #[test]
+    fn invalid_digit() {
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 2 }),
+            atonum_u32(b"10A", Radix::DEC)
+        );
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 1 }),
+            atonum_u32(b"12", Radix::BIN)
+        );
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 0 }),
+            atonum_u32(b"-1", Radix::DEC)
+        );
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 1 }),
+            atonum_i32(b"--1", Radix::DEC)
+        );
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 3 }),
+            atonum_i8(b"-12 ", Radix::DEC)
+        );
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 0 }),
+            atonum_u64(b"\xFF", Radix::MAX)
+        );
+        // Invalid digits are still reported after saturating.
+        assert_eq!(
+            Err(AToNumError::InvalidDigit { index: 4 }),
+            atonum_u8_saturating(b"9999!", Radix::DEC)
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Err(AToNumError::Overflow), atonum_u8(b"256", Radix::DEC));
+        assert_eq!(
+            Err(AToNumError::Overflow),
+            atonum_u8(b"100000000", Radix::BIN)
+        );
+        assert_eq!(Err(AToNumError::Overflow), atonum_i8(b"128", Radix::DEC));
+        assert_eq!(Err(AToNumError::Overflow), atonum_i8(b"-129", Radix::DEC));
+        assert_eq!(
+            Err(AToNumError::Overflow),
+            atonum_u64(b"18446744073709551616", Radix::DEC)
+        );
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(Ok(u8::MAX), atonum_u8_saturating(b"256", Radix::DEC));
+        assert_eq!(
+            Ok(u8::MAX),
+            atonum_u8_saturating(b"99999999999", Radix::DEC)
+        );
+        assert_eq!(Ok(i8::MAX), atonum_i8_saturating(b"128", Radix::DEC));
+        assert_eq!(Ok(i8::MIN), atonum_i8_saturating(b"-129", Radix::DEC));
+        assert_eq!(
+            Ok(i8::MIN),
+            atonum_i8_saturating(b"-99999999999", Radix::DEC)
+        );
+        assert_eq!(Ok(42), atonum_i8_saturating(b"42", Radix::DEC));
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(Ok(0), atonum_u8_wrapping(b"256", Radix::DEC));
+        assert_eq!(Ok(0xCD), atonum_u8_wrapping(b"ABCD", Radix::HEX));
+        assert_eq!(Ok(i8::MIN), atonum_i8_wrapping(b"128", Radix::DEC));
+        assert_eq!(Ok(i8::MAX), atonum_i8_wrapping(b"-129", Radix::DEC));
+        assert_eq!(Ok(-1), atonum_i16_wrapping(b"FFFF", Radix::HEX));
+        assert_eq!(Ok(1), atonum_i16_wrapping(b"-FFFF", Radix::HEX));
+    }
+
+    #[test]
+    fn const_context() {
+        const PARSED: u32 = match atonum_u32(b"DEADBEEF", Radix::HEX) {
+            Ok(value) => value,
+            Err(_) => panic!("invalid constant"),
+        };
+        assert_eq!(0xDEADBEEF, PARSED);
+    }
+
+    #[test]
+    fn round_trip_i16_all_bases() {
+        let mut buffer = [0u8; 17];
+        for base in 2..=36 {
+            let radix = Radix::new(base);
+            for i in i16::MIN..=i16::MAX {
+                let string = numtoa_i16_radix(i, radix, &mut buffer);
+                assert_eq!(Ok(i), atonum_i16(string, radix));
+            }
+        }
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn round_trip_wide() {
+        let mut buffer = [0u8; 129];
+        for base in 2..=36 {
+            let radix = Radix::new(base);
+            for i in [0, 1, u64::MAX / 3, u64::MAX - 1, u64::MAX] {
+                let string = numtoa_u64_radix(i, radix, &mut buffer);
+                assert_eq!(Ok(i), atonum_u64(string, radix));
+            }
+            for i in [i128::MIN, i128::MIN + 1, -1, 0, i128::MAX] {
+                let string = numtoa_i128_with_alphabet(i, radix, &Alphabet::LOWER, &mut buffer);
+                assert_eq!(Ok(i), atonum_i128(string, radix));
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::atonum_core::*;
+use crate::numtoa_error::AToNumError;
+use crate::numtoa_radix::Radix;
+
+/// Parses a number from its ASCII representation, as written by [NumToA](crate::NumToA).
+pub trait AToNum: Sized {
+    /// Parses a number from the given ASCII digits, which may be of either case, in the given radix. Signed types
+    /// accept an optional leading `+` or `-`, whereas unsigned types only accept a leading `+`.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{AToNum, AToNumError, NumToA, Radix};
+    ///
+    /// let mut buffer = [0u8; 20];
+    /// let string = (-256123i32).numtoa(16, &mut buffer);
+    /// assert_eq!(i32::atonum(string, Radix::HEX), Ok(-256123));
+    /// assert_eq!(u32::atonum(b"3e87b", Radix::HEX), Ok(256123));
+    ///
+    /// assert_eq!(u8::atonum(b"", Radix::DEC), Err(AToNumError::Empty));
+    /// assert_eq!(u8::atonum(b"12a", Radix::DEC), Err(AToNumError::InvalidDigit { index: 2 }));
+    /// assert_eq!(u8::atonum(b"256", Radix::DEC), Err(AToNumError::Overflow));
+    /// ```
+    fn atonum(string: &[u8], radix: Radix) -> Result<Self, AToNumError>;
+
+    /// Equivalent to [AToNum::atonum], but values which are out of range saturate at the numeric bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{AToNum, Radix};
+    ///
+    /// assert_eq!(u8::atonum_saturating(b"256", Radix::DEC), Ok(255));
+    /// assert_eq!(i8::atonum_saturating(b"-129", Radix::DEC), Ok(-128));
+    /// ```
+    fn atonum_saturating(string: &[u8], radix: Radix) -> Result<Self, AToNumError>;
+
+    /// Equivalent to [AToNum::atonum], but values which are out of range wrap around at the numeric bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{AToNum, Radix};
+    ///
+    /// assert_eq!(u8::atonum_wrapping(b"256", Radix::DEC), Ok(0));
+    /// assert_eq!(i8::atonum_wrapping(b"128", Radix::DEC), Ok(-128));
+    /// ```
+    fn atonum_wrapping(string: &[u8], radix: Radix) -> Result<Self, AToNumError>;
+}
+
+macro_rules! impl_atonum_trait {
+    (
+        $type_name:ty,
+        $core_function_name:ident,
+        $saturating_function_name:ident,
+        $wrapping_function_name:ident
+    ) => {
+        impl AToNum for $type_name {
+            fn atonum(string: &[u8], radix: Radix) -> Result<$type_name, AToNumError> {
+                $core_function_name(string, radix)
+            }
+
+            fn atonum_saturating(string: &[u8], radix: Radix) -> Result<$type_name, AToNumError> {
+                $saturating_function_name(string, radix)
+            }
+
+            fn atonum_wrapping(string: &[u8], radix: Radix) -> Result<$type_name, AToNumError> {
+                $wrapping_function_name(string, radix)
+            }
+        }
+    };
+}
+
+impl_atonum_trait!(i8, atonum_i8, atonum_i8_saturating, atonum_i8_wrapping);
+impl_atonum_trait!(i16, atonum_i16, atonum_i16_saturating, atonum_i16_wrapping);
+impl_atonum_trait!(i32, atonum_i32, atonum_i32_saturating, atonum_i32_wrapping);
+impl_atonum_trait!(i64, atonum_i64, atonum_i64_saturating, atonum_i64_wrapping);
+#[cfg(feature = "u128")]
+impl_atonum_trait!(
+    i128,
+    atonum_i128,
+    atonum_i128_saturating,
+    atonum_i128_wrapping
+);
+impl_atonum_trait!(
+    isize,
+    atonum_isize,
+    atonum_isize_saturating,
+    atonum_isize_wrapping
+);
+impl_atonum_trait!(u8, atonum_u8, atonum_u8_saturating, atonum_u8_wrapping);
+impl_atonum_trait!(u16, atonum_u16, atonum_u16_saturating, atonum_u16_wrapping);
+impl_atonum_trait!(u32, atonum_u32, atonum_u32_saturating, atonum_u32_wrapping);
+impl_atonum_trait!(u64, atonum_u64, atonum_u64_saturating, atonum_u64_wrapping);
+#[cfg(feature = "u128")]
+impl_atonum_trait!(
+    u128,
+    atonum_u128,
+    atonum_u128_saturating,
+    atonum_u128_wrapping
+);
+impl_atonum_trait!(
+    usize,
+    atonum_usize,
+    atonum_usize_saturating,
+    atonum_usize_wrapping
+);
+
+#[test]
+fn atonum_trait() {
+    assert_eq!(u8::atonum(b"255", Radix::DEC), Ok(255));
+    assert_eq!(i64::atonum(b"-8000000000000000", Radix::HEX), Ok(i64::MIN));
+    assert_eq!(usize::atonum(b"+Z", Radix::MAX), Ok(35));
+    assert_eq!(i16::atonum(b"", Radix::DEC), Err(AToNumError::Empty));
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn atonum_trait_overflow_modes() {
+    assert_eq!(
+        u16::atonum(b"65536", Radix::DEC),
+        Err(AToNumError::Overflow)
+    );
+    assert_eq!(u16::atonum_saturating(b"65536", Radix::DEC), Ok(u16::MAX));
+    assert_eq!(u16::atonum_wrapping(b"65536", Radix::DEC), Ok(0));
+    assert_eq!(
+        i128::atonum_saturating(b"-1000000000000000000000000000000000000000", Radix::DEC),
+        Ok(i128::MIN)
+    );
+}
+
+#[test]
+fn atonum_trait_round_trip() {
+    use crate::NumToA;
+
+    let mut buffer = [0u8; 20];
+    for i in i8::MIN..=i8::MAX {
+        assert_eq!(i8::atonum(i.numtoa(10, &mut buffer), Radix::DEC), Ok(i));
+    }
+    for i in u8::MIN..=u8::MAX {
+        assert_eq!(u8::atonum(i.numtoa(16, &mut buffer), Radix::HEX), Ok(i));
+    }
+}
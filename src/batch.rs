@@ -0,0 +1,473 @@
+//! Conversion of whole slices of integers into a single buffer, separated by a delimiter.
+//!
+//! On `x86_64`, the lowest sixteen digits of each number are converted at once with SSE2, or two numbers at a time
+//! with AVX2 when the processor supports it. Other targets convert eight digits at a time with scalar arithmetic.
+//!
+//! # Example
+//! ```
+//! use numtoa::batch;
+//!
+//! let mut buffer = [0u8; 64];
+//! let mut offsets = [0usize; 4];
+//! let length = batch::format_i32_slice(&[7, -42, 1000, i32::MIN], b',', &mut buffer, &mut offsets).unwrap();
+//! assert_eq!(&buffer[..length], b"7,-42,1000,-2147483648");
+//! assert_eq!(offsets, [0, 2, 6, 11]);
+//! ```
+
+use crate::numtoa_core::{count_digits_u64, numtoa_u64_left, swar_8_digits};
+use crate::numtoa_error::NumToAError;
+
+const POW10_8: u64 = 100_000_000;
+const POW10_16: u64 = POW10_8 * POW10_8;
+
+// The widest conversion supported by the processor.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Simd {
+    #[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Sse2,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+}
+
+impl Simd {
+    fn detect() -> Simd {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if x86::has_avx2() {
+                Simd::Avx2
+            } else {
+                Simd::Sse2
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            Simd::Scalar
+        }
+    }
+}
+
+// Splits the lowest sixteen digits of a number into two numbers of eight digits.
+const fn split_digits(num: u64) -> (u32, u32) {
+    let low = num % POW10_16;
+    ((low / POW10_8) as u32, (low % POW10_8) as u32)
+}
+
+// Converts two numbers below 10^8 into sixteen ASCII digits, including leading zeros.
+fn digits_16(simd: Simd, (high, low): (u32, u32)) -> [u8; 16] {
+    match simd {
+        #[cfg(target_arch = "x86_64")]
+        // SSE2 is always available on `x86_64`.
+        Simd::Sse2 | Simd::Avx2 => unsafe { x86::digits_16_sse2(high, low) },
+        Simd::Scalar => {
+            let mut digits = [0u8; 16];
+            digits[..8].copy_from_slice(&swar_8_digits(high).to_le_bytes());
+            digits[8..].copy_from_slice(&swar_8_digits(low).to_le_bytes());
+            digits
+        }
+    }
+}
+
+// The conversion functions are safe `#[target_feature]` functions, which call the intrinsics of their features
+// outside of `unsafe` blocks. This needs Rust 1.87, as declared by the `rust-version` of the crate.
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use core::arch::x86_64::*;
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    // Whether AVX2 is usable: zero before it has been detected, and otherwise one without and two with AVX2.
+    static AVX2: AtomicU8 = AtomicU8::new(0);
+
+    pub(super) fn has_avx2() -> bool {
+        match AVX2.load(Ordering::Relaxed) {
+            0 => {
+                let detected = detect_avx2();
+                AVX2.store(1 + detected as u8, Ordering::Relaxed);
+                detected
+            }
+            state => state == 2,
+        }
+    }
+
+    fn detect_avx2() -> bool {
+        // The processor must support AVX and AVX2, and the operating system must save the YMM registers.
+        let features = __cpuid(1);
+        let (xsave, avx) = (features.ecx & 1 << 27 != 0, features.ecx & 1 << 28 != 0);
+        if !xsave || !avx || __get_cpuid_max(0).0 < 7 {
+            return false;
+        }
+        let ymm = unsafe { xgetbv() } & 0b110 == 0b110;
+        ymm && __cpuid_count(7, 0).ebx & 1 << 5 != 0
+    }
+
+    #[target_feature(enable = "xsave")]
+    unsafe fn xgetbv() -> u64 {
+        _xgetbv(0)
+    }
+
+    // Each step divides the four digit halves `abcd` and `efgh` of a number below 10^8 by a power of ten in every
+    // 16 bit lane, approximating the division by multiplying by a reciprocal and keeping the high bits:
+    //
+    // [abcd, abcd, abcd, abcd, efgh, efgh, efgh, efgh] / [1000, 100, 10, 1, ...] = [a, ab, abc, abcd, e, ...]
+    //
+    // Subtracting ten times the lane to the left leaves one digit per lane. The reciprocals are exact for every
+    // four digit number once the lanes are scaled by four.
+    const DIVISORS: [i16; 8] = [8389, 5243, 13108, -32768, 8389, 5243, 13108, -32768];
+    const SHIFTS: [i16; 8] = [
+        1 << 7,
+        1 << 11,
+        1 << 13,
+        -32768,
+        1 << 7,
+        1 << 11,
+        1 << 13,
+        -32768,
+    ];
+    // ceil(2^45 / 10^4), which divides every number below 10^8 exactly.
+    const DIV_10000: i32 = 0xD1B7_1759u32 as i32;
+
+    // Converts the number below 10^8 in the low 32 bits of each 128 bit lane into eight 16 bit digits.
+    #[target_feature(enable = "sse2")]
+    fn digits_8_sse2(num: __m128i) -> __m128i {
+        let divisors = _mm_setr_epi16(
+            DIVISORS[0],
+            DIVISORS[1],
+            DIVISORS[2],
+            DIVISORS[3],
+            DIVISORS[4],
+            DIVISORS[5],
+            DIVISORS[6],
+            DIVISORS[7],
+        );
+        let shifts = _mm_setr_epi16(
+            SHIFTS[0], SHIFTS[1], SHIFTS[2], SHIFTS[3], SHIFTS[4], SHIFTS[5], SHIFTS[6], SHIFTS[7],
+        );
+        let abcd = _mm_srli_epi64(_mm_mul_epu32(num, _mm_set1_epi32(DIV_10000)), 45);
+        let efgh = _mm_sub_epi32(num, _mm_mul_epu32(abcd, _mm_set1_epi32(10000)));
+        let halves = _mm_slli_epi64(_mm_unpacklo_epi16(abcd, efgh), 2);
+        let pairs = _mm_unpacklo_epi16(halves, halves);
+        let lanes = _mm_unpacklo_epi32(pairs, pairs);
+        let quotients = _mm_mulhi_epu16(_mm_mulhi_epu16(lanes, divisors), shifts);
+        let tens = _mm_slli_epi64(_mm_mullo_epi16(quotients, _mm_set1_epi16(10)), 16);
+        _mm_sub_epi16(quotients, tens)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) fn digits_16_sse2(high: u32, low: u32) -> [u8; 16] {
+        let high = digits_8_sse2(_mm_cvtsi32_si128(high as i32));
+        let low = digits_8_sse2(_mm_cvtsi32_si128(low as i32));
+        let digits = _mm_add_epi8(_mm_packus_epi16(high, low), _mm_set1_epi8(b'0' as i8));
+        let mut bytes = [0u8; 16];
+        unsafe { _mm_storeu_si128(bytes.as_mut_ptr() as *mut __m128i, digits) };
+        bytes
+    }
+
+    // The AVX2 equivalent of `digits_8_sse2`, which converts a number in each of the two 128 bit lanes.
+    #[target_feature(enable = "avx2")]
+    fn digits_8_avx2(num: __m256i) -> __m256i {
+        let divisors = _mm256_setr_epi16(
+            DIVISORS[0],
+            DIVISORS[1],
+            DIVISORS[2],
+            DIVISORS[3],
+            DIVISORS[4],
+            DIVISORS[5],
+            DIVISORS[6],
+            DIVISORS[7],
+            DIVISORS[0],
+            DIVISORS[1],
+            DIVISORS[2],
+            DIVISORS[3],
+            DIVISORS[4],
+            DIVISORS[5],
+            DIVISORS[6],
+            DIVISORS[7],
+        );
+        let shifts = _mm256_setr_epi16(
+            SHIFTS[0], SHIFTS[1], SHIFTS[2], SHIFTS[3], SHIFTS[4], SHIFTS[5], SHIFTS[6], SHIFTS[7],
+            SHIFTS[0], SHIFTS[1], SHIFTS[2], SHIFTS[3], SHIFTS[4], SHIFTS[5], SHIFTS[6], SHIFTS[7],
+        );
+        let abcd = _mm256_srli_epi64(_mm256_mul_epu32(num, _mm256_set1_epi32(DIV_10000)), 45);
+        let efgh = _mm256_sub_epi32(num, _mm256_mul_epu32(abcd, _mm256_set1_epi32(10000)));
+        let halves = _mm256_slli_epi64(_mm256_unpacklo_epi16(abcd, efgh), 2);
+        let pairs = _mm256_unpacklo_epi16(halves, halves);
+        let lanes = _mm256_unpacklo_epi32(pairs, pairs);
+        let quotients = _mm256_mulhi_epu16(_mm256_mulhi_epu16(lanes, divisors), shifts);
+        let tens = _mm256_slli_epi64(_mm256_mullo_epi16(quotients, _mm256_set1_epi16(10)), 16);
+        _mm256_sub_epi16(quotients, tens)
+    }
+
+    // Converts the sixteen digits of two numbers at once.
+    #[target_feature(enable = "avx2")]
+    pub(super) fn digits_16x2_avx2(first: (u32, u32), second: (u32, u32)) -> [[u8; 16]; 2] {
+        let high = _mm256_setr_epi32(first.0 as i32, 0, 0, 0, second.0 as i32, 0, 0, 0);
+        let low = _mm256_setr_epi32(first.1 as i32, 0, 0, 0, second.1 as i32, 0, 0, 0);
+        let digits = _mm256_add_epi8(
+            _mm256_packus_epi16(digits_8_avx2(high), digits_8_avx2(low)),
+            _mm256_set1_epi8(b'0' as i8),
+        );
+        let mut bytes = [[0u8; 16]; 2];
+        unsafe { _mm256_storeu_si256(bytes.as_mut_ptr() as *mut __m256i, digits) };
+        bytes
+    }
+}
+
+// Writes the separator, the sign and the digits of a number, recording where it starts. The lowest sixteen digits
+// have already been converted, so that only the leading digits of larger numbers remain.
+fn write_number(
+    (negative, num): (bool, u64),
+    digits: &[u8; 16],
+    index: usize,
+    separator: u8,
+    string: &mut [u8],
+    offsets: &mut [usize],
+    mut position: usize,
+) -> usize {
+    if index > 0 {
+        string[position] = separator;
+        position += 1;
+    }
+    offsets[index] = position;
+    if negative {
+        string[position] = b'-';
+        position += 1;
+    }
+
+    let length = count_digits_u64(num, 10);
+    if length > 16 {
+        position += numtoa_u64_left(num / POW10_16, 10, &mut string[position..]);
+        string[position..position + 16].copy_from_slice(digits);
+        position + 16
+    } else {
+        string[position..position + length].copy_from_slice(&digits[16 - length..]);
+        position + length
+    }
+}
+
+// Writes `count` numbers given as their sign and magnitude.
+fn format_slice<F: Fn(usize) -> (bool, u64)>(
+    simd: Simd,
+    count: usize,
+    number: F,
+    separator: u8,
+    string: &mut [u8],
+    offsets: &mut [usize],
+) -> Result<usize, NumToAError> {
+    assert!(
+        offsets.len() >= count,
+        "offsets must have room for every number"
+    );
+
+    let mut needed = count.saturating_sub(1);
+    for index in 0..count {
+        let (negative, num) = number(index);
+        needed += negative as usize + count_digits_u64(num, 10);
+    }
+    if string.len() < needed {
+        return Err(NumToAError::BufferTooSmall { needed });
+    }
+
+    let mut position = 0;
+    let mut index = 0;
+    #[cfg(target_arch = "x86_64")]
+    if simd == Simd::Avx2 {
+        while index + 2 <= count {
+            let (first, second) = (number(index), number(index + 1));
+            // AVX2 support was detected at runtime.
+            let digits =
+                unsafe { x86::digits_16x2_avx2(split_digits(first.1), split_digits(second.1)) };
+            position = write_number(
+                first, &digits[0], index, separator, string, offsets, position,
+            );
+            position = write_number(
+                second,
+                &digits[1],
+                index + 1,
+                separator,
+                string,
+                offsets,
+                position,
+            );
+            index += 2;
+        }
+    }
+    while index < count {
+        let number = number(index);
+        let digits = digits_16(simd, split_digits(number.1));
+        position = write_number(number, &digits, index, separator, string, offsets, position);
+        index += 1;
+    }
+
+    Ok(position)
+}
+
+macro_rules! impl_format_slice {
+    ($type_name:ty, $function_name:ident, $sign_and_magnitude:expr) => {
+        /// Writes every number in base 10 into the start of `string`, separated by `separator`, and returns the
+        /// number of bytes written. The index of the first byte of each number is stored into `offsets`.
+        ///
+        /// # Errors
+        /// If `string` is too small to hold every number, in which case nothing is written.
+        ///
+        /// # Panics
+        /// If `offsets` is shorter than `values`.
+        pub fn $function_name(
+            values: &[$type_name],
+            separator: u8,
+            string: &mut [u8],
+            offsets: &mut [usize],
+        ) -> Result<usize, NumToAError> {
+            format_slice(
+                Simd::detect(),
+                values.len(),
+                |index| $sign_and_magnitude(values[index]),
+                separator,
+                string,
+                offsets,
+            )
+        }
+    };
+}
+
+impl_format_slice!(u32, format_u32_slice, |num: u32| (false, num as u64));
+impl_format_slice!(u64, format_u64_slice, |num: u64| (false, num));
+impl_format_slice!(i32, format_i32_slice, |num: i32| (
+    num < 0,
+    num.unsigned_abs() as u64
+));
+impl_format_slice!(i64, format_i64_slice, |num: i64| (
+    num < 0,
+    num.unsigned_abs()
+));
+
+#[cfg(test)]
+mod batch_test {
+    use super::*;
+    use crate::numtoa_core::{numtoa_i64, numtoa_u64};
+
+    fn levels() -> [Simd; 3] {
+        #[cfg(target_arch = "x86_64")]
+        {
+            let widest = if x86::has_avx2() {
+                Simd::Avx2
+            } else {
+                Simd::Sse2
+            };
+            [Simd::Scalar, Simd::Sse2, widest]
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            [Simd::Scalar; 3]
+        }
+    }
+
+    fn random_values() -> [i64; 257] {
+        let mut values = [0i64; 257];
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        for (index, value) in values.iter_mut().enumerate() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *value = (state >> (state % 64)) as i64;
+            if index % 3 == 0 {
+                *value = value.wrapping_neg();
+            }
+        }
+        values[..6].copy_from_slice(&[
+            0,
+            -1,
+            i64::MIN,
+            i64::MAX,
+            9_999_999_999_999_999,
+            10_000_000_000_000_000,
+        ]);
+        values
+    }
+
+    #[test]
+    fn matches_scalar() {
+        let values = random_values();
+        let mut expected = [0u8; 24];
+        let mut string = [0u8; 257 * 21];
+        let mut offsets = [0usize; 257];
+        for simd in levels() {
+            let length = format_slice(
+                simd,
+                values.len(),
+                |index| (values[index] < 0, values[index].unsigned_abs()),
+                b' ',
+                &mut string,
+                &mut offsets,
+            )
+            .unwrap();
+            for (index, &value) in values.iter().enumerate() {
+                let number = numtoa_i64(value, 10, &mut expected);
+                let start = offsets[index];
+                assert_eq!(number, &string[start..start + number.len()], "{:?}", simd);
+                let end = if index + 1 < values.len() {
+                    offsets[index + 1] - 1
+                } else {
+                    length
+                };
+                assert_eq!(start + number.len(), end);
+            }
+
+            let length = format_slice(
+                simd,
+                values.len(),
+                |index| (false, values[index] as u64),
+                b'\n',
+                &mut string,
+                &mut offsets,
+            )
+            .unwrap();
+            for (index, &value) in values.iter().enumerate() {
+                let number = numtoa_u64(value as u64, 10, &mut expected);
+                assert_eq!(
+                    number,
+                    &string[offsets[index]..offsets[index] + number.len()]
+                );
+            }
+            assert_eq!(b'\n', string[offsets[1] - 1]);
+            assert_eq!(
+                length,
+                offsets[256] + numtoa_u64(values[256] as u64, 10, &mut expected).len()
+            );
+        }
+    }
+
+    #[test]
+    fn public_functions() {
+        let mut string = [0u8; 64];
+        let mut offsets = [0usize; 8];
+        let length = format_u32_slice(&[0, u32::MAX, 10], b',', &mut string, &mut offsets).unwrap();
+        assert_eq!(b"0,4294967295,10", &string[..length]);
+        assert_eq!([0, 2, 13], offsets[..3]);
+        let length = format_u64_slice(&[u64::MAX], b',', &mut string, &mut offsets).unwrap();
+        assert_eq!(b"18446744073709551615", &string[..length]);
+        let length = format_i64_slice(&[i64::MIN, 5], b';', &mut string, &mut offsets).unwrap();
+        assert_eq!(b"-9223372036854775808;5", &string[..length]);
+        assert_eq!(
+            Ok(0),
+            format_i32_slice(&[], b',', &mut string, &mut offsets)
+        );
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut string = [0u8; 10];
+        let mut offsets = [0usize; 2];
+        assert_eq!(
+            Err(NumToAError::BufferTooSmall { needed: 11 }),
+            format_i32_slice(&[-1234, 56789], b',', &mut string, &mut offsets)
+        );
+        assert_eq!([0u8; 10], string);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_offsets() {
+        let _ = format_u32_slice(&[1, 2, 3], b',', &mut [0u8; 8], &mut [0usize; 2]);
+    }
+}
@@ -0,0 +1,216 @@
+// A fixed-capacity unsigned big integer, large enough to hold the exact decimal digits of any `f64`.
+//
+// The largest value held is a 53-bit mantissa multiplied by `5^1074`, which needs 2547 bits.
+
+const LIMBS: usize = 80;
+
+// The largest power of five which fits in a limb.
+const POW5_13: u32 = 1_220_703_125;
+
+// The largest power of ten which fits in a limb.
+pub(crate) const POW10_9: u32 = 1_000_000_000;
+
+#[derive(Clone, Copy)]
+pub(crate) struct Big {
+    // Little endian limbs, of which only the first `len` may be non-zero.
+    limbs: [u32; LIMBS],
+    len: usize,
+}
+
+impl Big {
+    pub(crate) const fn from_u64(value: u64) -> Big {
+        let mut big = Big {
+            limbs: [0; LIMBS],
+            len: 2,
+        };
+        big.limbs[0] = value as u32;
+        big.limbs[1] = (value >> 32) as u32;
+        big.trim();
+        big
+    }
+
+    // Drops the most significant zero limbs.
+    const fn trim(&mut self) {
+        while self.len > 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    pub(crate) const fn is_zero(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) const fn is_odd(&self) -> bool {
+        self.limbs[0] & 1 == 1
+    }
+
+    pub(crate) const fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0u64;
+        let mut index = 0;
+        while index < self.len {
+            let product = self.limbs[index] as u64 * factor as u64 + carry;
+            self.limbs[index] = product as u32;
+            carry = product >> 32;
+            index += 1;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    pub(crate) const fn mul_pow5(&mut self, mut exponent: u32) {
+        while exponent >= 13 {
+            self.mul_small(POW5_13);
+            exponent -= 13;
+        }
+        let mut factor = 1;
+        while exponent > 0 {
+            factor *= 5;
+            exponent -= 1;
+        }
+        self.mul_small(factor);
+    }
+
+    pub(crate) const fn mul_pow2(&mut self, exponent: u32) {
+        if self.is_zero() {
+            return;
+        }
+
+        let limbs = (exponent / 32) as usize;
+        let bits = exponent % 32;
+        let mut index = self.len;
+        if bits != 0 {
+            self.limbs[self.len] = 0;
+            index += 1;
+        }
+        // Move limbs from the most significant end, so that none are overwritten before they are read.
+        while index > 0 {
+            index -= 1;
+            let mut limb = self.limbs[index] << bits;
+            if bits != 0 && index > 0 {
+                limb |= self.limbs[index - 1] >> (32 - bits);
+            }
+            self.limbs[index + limbs] = limb;
+        }
+        while index < limbs {
+            self.limbs[index] = 0;
+            index += 1;
+        }
+        self.len += limbs + (bits != 0) as usize;
+        self.trim();
+    }
+
+    pub(crate) const fn add_small(&mut self, value: u32) {
+        let mut carry = value;
+        let mut index = 0;
+        while carry != 0 {
+            if index == self.len {
+                self.limbs[index] = carry;
+                self.len += 1;
+                return;
+            }
+            let (sum, overflow) = self.limbs[index].overflowing_add(carry);
+            self.limbs[index] = sum;
+            carry = overflow as u32;
+            index += 1;
+        }
+    }
+
+    // Divides by `divisor` in place, returning the remainder.
+    pub(crate) const fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        let mut index = self.len;
+        while index > 0 {
+            index -= 1;
+            let dividend = (remainder << 32) | self.limbs[index] as u64;
+            self.limbs[index] = (dividend / divisor as u64) as u32;
+            remainder = dividend % divisor as u64;
+        }
+        self.trim();
+        remainder as u32
+    }
+
+    // Divides by `10^exponent` in place, rounding exact ties to even.
+    pub(crate) const fn div_pow10_round(&mut self, mut exponent: usize) {
+        if exponent == 0 {
+            return;
+        }
+
+        let mut sticky = false;
+        while exponent > 9 {
+            sticky |= self.div_rem_small(POW10_9) != 0;
+            exponent -= 9;
+        }
+        let divisor = 10u32.pow(exponent as u32);
+        let remainder = self.div_rem_small(divisor);
+        let half = divisor / 2;
+        if remainder > half || (remainder == half && (sticky || self.is_odd())) {
+            self.add_small(1);
+        }
+    }
+
+    // Counts the number of decimal digits, where zero has one digit.
+    pub(crate) const fn digits(&self) -> usize {
+        let mut value = *self;
+        let mut digits = 0;
+        while value.len > 1 || (value.len == 1 && value.limbs[0] >= POW10_9) {
+            value.div_rem_small(POW10_9);
+            digits += 9;
+        }
+        let mut last = value.limbs[0];
+        loop {
+            digits += 1;
+            last /= 10;
+            if last == 0 {
+                return digits;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod bignum_test {
+    use super::*;
+
+    #[test]
+    fn shifts_and_digits() {
+        let mut big = Big::from_u64(u64::MAX);
+        big.mul_pow2(64);
+        big.add_small(1);
+        // 2^128 - 2^64 + 1
+        assert_eq!(39, big.digits());
+        assert_eq!(
+            (340282366920938463444927863358058659841u128 % 1_000_000_000) as u32,
+            big.div_rem_small(POW10_9)
+        );
+        assert_eq!(1, Big::from_u64(0).digits());
+        assert_eq!(10, Big::from_u64(1_000_000_000).digits());
+    }
+
+    #[test]
+    fn round_half_even() {
+        for (value, exponent, expected) in [
+            (125u64, 1, 12u64),
+            (135, 1, 14),
+            (2_500_000_000_000_000_000, 18, 2),
+            (2_500_000_000_000_000_001, 18, 3),
+            (1_500_000_000_000_000_000, 18, 2),
+            (999, 3, 1),
+            (499, 3, 0),
+        ] {
+            let mut big = Big::from_u64(value);
+            big.div_pow10_round(exponent);
+            let mut expected = Big::from_u64(expected);
+            assert_eq!(expected.div_rem_small(POW10_9), big.div_rem_small(POW10_9));
+            assert!(big.is_zero());
+        }
+    }
+
+    #[test]
+    fn largest_value() {
+        let mut big = Big::from_u64((1 << 53) - 1);
+        big.mul_pow5(1074);
+        assert_eq!(767, big.digits());
+    }
+}
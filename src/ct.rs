@@ -0,0 +1,160 @@
+//! Constant-time conversion of unsigned integers in bases 16 and 10, for values such as keys and nonces which must not
+//! leak through timing.
+//!
+//! Every number is written at the full width of its type, padded with leading zeros, using only arithmetic on the
+//! number: there are no branches and no table lookups which depend on its value. The hexadecimal digits are those of
+//! [Alphabet::UPPER](crate::Alphabet::UPPER), so the output matches that of `numtoa` padded with zeros.
+//!
+//! # Example
+//! ```
+//! use numtoa::ct;
+//!
+//! assert_eq!(&ct::hex_u32(0xC0FFEE), b"00C0FFEE");
+//! assert_eq!(&ct::dec_u16(42), b"00042");
+//! ```
+
+// The number of binary coded decimal digits held by each word during decimal conversion.
+const BCD_DIGITS: usize = 16;
+
+// Converts a value below 16 into an uppercase hexadecimal digit, adding the gap between `9` and `A` to values above 9
+// through the sign bit of `9 - value` rather than a comparison.
+const fn hex_digit(value: u8) -> u8 {
+    b'0' + value + (9u8.wrapping_sub(value) >> 7) * (b'A' - b'9' - 1)
+}
+
+// Doubles every word of binary coded decimal digits after adding three to each digit above four, shifting `bit` into
+// the lowest digit. This is one step of the double dabble algorithm, performed on sixteen digits at a time.
+const fn double_dabble<const WORDS: usize>(bcd: &mut [u64; WORDS], bit: u64) {
+    let mut carry = bit;
+    let mut word = 0;
+    while word < WORDS {
+        // A digit above four has its high bit set once three is added, which cannot carry into the next digit.
+        let adjust = (bcd[word] + 0x3333_3333_3333_3333) & 0x8888_8888_8888_8888;
+        let digits = bcd[word] + (adjust >> 2) + (adjust >> 3);
+        bcd[word] = digits << 1 | carry;
+        carry = digits >> 63;
+        word += 1;
+    }
+}
+
+macro_rules! impl_ct_for {
+    ($type_name:ty, $hex_function_name:ident, $dec_function_name:ident) => {
+        #[doc = concat!("Writes the [", stringify!($type_name), "] in base 16 at the full width of its type, in constant time.")]
+        pub const fn $hex_function_name(num: $type_name) -> [u8; <$type_name>::BITS as usize / 4] {
+            const WIDTH: usize = <$type_name>::BITS as usize / 4;
+            let mut string = [0u8; WIDTH];
+            let mut index = 0;
+            while index < WIDTH {
+                let nibble = (num >> (4 * index)) as u8 & 0xF;
+                string[WIDTH - 1 - index] = hex_digit(nibble);
+                index += 1;
+            }
+            string
+        }
+
+        #[doc = concat!("Writes the [", stringify!($type_name), "] in base 10 at the full width of its type, in constant time.")]
+        pub const fn $dec_function_name(
+            num: $type_name,
+        ) -> [u8; <$type_name>::MAX.ilog10() as usize + 1] {
+            const WIDTH: usize = <$type_name>::MAX.ilog10() as usize + 1;
+            const WORDS: usize = WIDTH.div_ceil(BCD_DIGITS);
+            let mut bcd = [0u64; WORDS];
+            let mut bit = <$type_name>::BITS;
+            while bit > 0 {
+                bit -= 1;
+                double_dabble(&mut bcd, (num >> bit) as u64 & 1);
+            }
+
+            let mut string = [0u8; WIDTH];
+            let mut index = 0;
+            while index < WIDTH {
+                let digit = (bcd[index / BCD_DIGITS] >> (4 * (index % BCD_DIGITS))) as u8 & 0xF;
+                string[WIDTH - 1 - index] = b'0' + digit;
+                index += 1;
+            }
+            string
+        }
+    };
+}
+
+impl_ct_for!(u8, hex_u8, dec_u8);
+impl_ct_for!(u16, hex_u16, dec_u16);
+impl_ct_for!(u32, hex_u32, dec_u32);
+impl_ct_for!(u64, hex_u64, dec_u64);
+impl_ct_for!(usize, hex_usize, dec_usize);
+#[cfg(feature = "u128")]
+impl_ct_for!(u128, hex_u128, dec_u128);
+
+#[cfg(test)]
+mod ct_test {
+    use super::*;
+    use crate::numtoa_core::*;
+
+    // Compares the constant-time functions of each type with `numtoa` writing into a buffer of zeros of the same
+    // width, for the extremes of the type and pseudo-random values of every magnitude.
+    macro_rules! verify_ct {
+        ($($type_name:ty, $core_function_name:ident, $hex_function_name:ident, $dec_function_name:ident);*) => {
+            let mut num = 1u64;
+            for _ in 0..20000 {
+                num = num.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let random = (num as u128) << 64 | num.rotate_left(23) as u128;
+                $(
+                    for value in [0, 1, <$type_name>::MAX, (random >> (num % 128)) as $type_name] {
+                        let mut expected = [b'0'; <$type_name>::BITS as usize / 4];
+                        $core_function_name(value, 16, &mut expected);
+                        assert_eq!(expected, $hex_function_name(value));
+
+                        let mut expected = [b'0'; <$type_name>::MAX.ilog10() as usize + 1];
+                        $core_function_name(value, 10, &mut expected);
+                        assert_eq!(expected, $dec_function_name(value));
+                    }
+                )*
+            }
+        };
+    }
+
+    #[test]
+    fn matches_padded_numtoa() {
+        verify_ct!(
+            u8, numtoa_u8, hex_u8, dec_u8;
+            u16, numtoa_u16, hex_u16, dec_u16;
+            u32, numtoa_u32, hex_u32, dec_u32;
+            u64, numtoa_u64, hex_u64, dec_u64;
+            usize, numtoa_usize, hex_usize, dec_usize
+        );
+        #[cfg(feature = "u128")]
+        verify_ct!(u128, numtoa_u128, hex_u128, dec_u128);
+    }
+
+    #[test]
+    fn exhaustive_16_bit() {
+        for value in 0..=u16::MAX {
+            let mut expected = [b'0'; 4];
+            numtoa_u16(value, 16, &mut expected);
+            assert_eq!(expected, hex_u16(value));
+
+            let mut expected = [b'0'; 5];
+            numtoa_u16(value, 10, &mut expected);
+            assert_eq!(expected, dec_u16(value));
+        }
+    }
+
+    #[test]
+    fn hex_digits() {
+        let digits: [u8; 16] = core::array::from_fn(|value| hex_digit(value as u8));
+        assert_eq!(&digits, b"0123456789ABCDEF");
+    }
+
+    #[test]
+    fn const_context() {
+        const HEX: [u8; 16] = hex_u64(0xDEAD_BEEF);
+        const DEC: [u8; 20] = dec_u64(u64::MAX);
+        assert_eq!(&HEX, b"00000000DEADBEEF");
+        assert_eq!(&DEC, b"18446744073709551615");
+        #[cfg(feature = "u128")]
+        assert_eq!(
+            &dec_u128(u128::MAX),
+            b"340282366920938463463374607431768211455"
+        );
+    }
+}
@@ -75,6 +75,15 @@
 
 #![no_std]
 
+mod numtoa_error;
+pub use numtoa_error::*;
+
+mod numtoa_radix;
+pub use numtoa_radix::*;
+
+mod numtoa_alphabet;
+pub use numtoa_alphabet::*;
+
 mod numtoa_core;
 pub use numtoa_core::*;
 
@@ -83,3 +92,23 @@ pub use numtoa_trait::*;
 
 mod numtoa_const;
 pub use numtoa_const::*;
+
+mod bignum;
+mod ryu;
+mod ryu_tables;
+
+mod numtoa_float;
+pub use numtoa_float::*;
+
+mod numtoa_buffer;
+pub use numtoa_buffer::*;
+
+pub mod batch;
+
+pub mod ct;
+
+mod atonum_core;
+pub use atonum_core::*;
+
+mod atonum_trait;
+pub use atonum_trait::*;
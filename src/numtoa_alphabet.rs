@@ -0,0 +1,294 @@
+use core::fmt::{self, Debug, Formatter};
+
+/// The set of ASCII digits used to represent each value of a digit, starting from zero.
+///
+/// Alphabets for bases up to 36 are built from a table of 36 digits with [Alphabet::new]. Larger alphabets of up to
+/// 85 digits, such as [Alphabet::BASE58] or [Alphabet::Z85], allow integers to be written in bases beyond 36 through
+/// the `numtoa_*_in_alphabet` functions.
+///
+/// # Example
+/// ```
+/// use numtoa::{Alphabet, NumToA, Radix};
+///
+/// const VOWELLESS: Alphabet = Alphabet::new(b"0123456789BCDFGHJKLMNPQRSTVWXZbcdfgh");
+///
+/// let mut buffer = [0u8; 20];
+/// assert_eq!(256123.numtoa_with_alphabet_str(Radix::HEX, &Alphabet::LOWER, &mut buffer), "3e87b");
+/// assert_eq!(0xABCD.numtoa_with_alphabet_str(Radix::HEX, &VOWELLESS, &mut buffer), "BCDF");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alphabet {
+    digits: [u8; Alphabet::MAX_DIGITS],
+    len: u8,
+    // Whether the first ten digits are `0` through `9`, enabling the optimized base 10 algorithm.
+    decimal: bool,
+    // Whether the first sixteen digits are standard hexadecimal digits, enabling the hexadecimal lookup tables.
+    hex: HexDigits,
+    // The number of leading digits shared with `Alphabet::UPPER`, up to which the pair lookup tables of the `fast`
+    // feature can be used.
+    upper: u8,
+}
+
+// The case of the standard hexadecimal digits at the start of an alphabet, if any.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum HexDigits {
+    Upper,
+    Lower,
+    Other,
+}
+
+// The digits of `Alphabet::UPPER`.
+const UPPER_DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Alphabet {
+    /// The largest number of digits that an alphabet may contain.
+    pub const MAX_DIGITS: usize = 85;
+
+    /// Digits followed by uppercase letters. This is the alphabet used when none is specified.
+    pub const UPPER: Alphabet = Alphabet::new(UPPER_DIGITS);
+    /// Digits followed by lowercase letters.
+    pub const LOWER: Alphabet = Alphabet::new(b"0123456789abcdefghijklmnopqrstuvwxyz");
+
+    /// The Bitcoin base58 alphabet, which omits the easily confused `0`, `O`, `I` and `l`.
+    pub const BASE58: Alphabet =
+        Alphabet::from_digits(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+    /// Digits followed by uppercase and then lowercase letters.
+    pub const BASE62: Alphabet =
+        Alphabet::from_digits(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+    /// The URL and filename safe base64 alphabet of RFC 4648, in its standard digit order.
+    pub const BASE64URL: Alphabet =
+        Alphabet::from_digits(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+    /// The ZeroMQ Z85 alphabet, which avoids quotes and backslashes so that it can be embedded in source code.
+    pub const Z85: Alphabet = Alphabet::from_digits(
+        b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#",
+    );
+    /// The Ascii85 alphabet, consisting of the characters `!` through `u`.
+    pub const ASCII85: Alphabet = Alphabet::from_digits(
+        b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu",
+    );
+
+    /// Creates an alphabet from a table of digits, where the byte at each index represents that value.
+    ///
+    /// # Panics
+    /// If any of the digits are not ASCII, or if any digit appears more than once. In const contexts, this is a
+    /// compile-time error.
+    pub const fn new(digits: &[u8; 36]) -> Alphabet {
+        Alphabet::from_digits(digits)
+    }
+
+    /// Creates an alphabet from a table of between 2 and 85 digits, where the byte at each index represents that
+    /// value. The number of digits is the largest base that the alphabet can represent.
+    ///
+    /// # Panics
+    /// If any of the digits are not ASCII, if any digit appears more than once, or if the number of digits is out of
+    /// range. In const contexts, this is a compile-time error.
+    ///
+    /// ```compile_fail
+    /// use numtoa::Alphabet;
+    ///
+    /// const REPEATED: Alphabet = Alphabet::from_digits(b"0123456789012345");
+    /// ```
+    pub const fn from_digits(digits: &[u8]) -> Alphabet {
+        assert!(
+            digits.len() >= 2 && digits.len() <= Alphabet::MAX_DIGITS,
+            "alphabets must have between 2 and 85 digits"
+        );
+
+        let mut table = [0u8; Alphabet::MAX_DIGITS];
+        let mut decimal = digits.len() >= 10;
+        let (mut upper, mut lower) = (digits.len() >= 16, digits.len() >= 16);
+        let mut upper_digits = 0;
+        // Whether each ASCII character has already been seen, so that every value has a distinct digit.
+        let mut seen = [false; 128];
+        let mut index = 0;
+        while index < digits.len() {
+            assert!(digits[index].is_ascii(), "alphabet digits must be ASCII");
+            assert!(
+                !seen[digits[index] as usize],
+                "alphabet digits must be distinct"
+            );
+            seen[digits[index] as usize] = true;
+            if upper_digits == index && index < 36 && digits[index] == UPPER_DIGITS[index] {
+                upper_digits += 1;
+            }
+            if index < 10 && digits[index] != b'0' + index as u8 {
+                decimal = false;
+            }
+            if index < 16 && digits[index] != b"0123456789ABCDEF"[index] {
+                upper = false;
+            }
+            if index < 16 && digits[index] != b"0123456789abcdef"[index] {
+                lower = false;
+            }
+            table[index] = digits[index];
+            index += 1;
+        }
+
+        Alphabet {
+            digits: table,
+            len: digits.len() as u8,
+            decimal,
+            hex: if upper {
+                HexDigits::Upper
+            } else if lower {
+                HexDigits::Lower
+            } else {
+                HexDigits::Other
+            },
+            upper: upper_digits as u8,
+        }
+    }
+
+    /// The table of digits in this alphabet.
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.digits.split_at(self.len as usize).0
+    }
+
+    /// The number of digits in this alphabet, which is also the largest base it can represent.
+    pub const fn max_base(&self) -> u32 {
+        self.len as u32
+    }
+
+    // The digit representing the given value.
+    pub(crate) const fn digit(&self, value: usize) -> u8 {
+        self.digits[value]
+    }
+
+    // Whether the optimized base 10 algorithm can be used with this alphabet.
+    pub(crate) const fn is_decimal(&self) -> bool {
+        self.decimal
+    }
+
+    // The number of leading digits shared with `Alphabet::UPPER`.
+    #[cfg_attr(not(all(feature = "fast", not(feature = "small"))), allow(dead_code))]
+    pub(crate) const fn upper_digits(&self) -> u32 {
+        self.upper as u32
+    }
+
+    // Which hexadecimal lookup table, if any, can be used with this alphabet.
+    #[cfg_attr(feature = "small", allow(dead_code))]
+    pub(crate) const fn hex_digits(&self) -> HexDigits {
+        self.hex
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Alphabet {
+        Alphabet::UPPER
+    }
+}
+
+impl Debug for Alphabet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Digits are validated to be ASCII on construction.
+        let digits = unsafe { core::str::from_utf8_unchecked(self.as_bytes()) };
+        f.debug_tuple("Alphabet").field(&digits).finish()
+    }
+}
+
+#[cfg(test)]
+mod alphabet_test {
+    use super::*;
+
+    #[test]
+    fn decimal_detection() {
+        assert!(Alphabet::UPPER.is_decimal());
+        assert!(Alphabet::LOWER.is_decimal());
+        assert!(Alphabet::BASE62.is_decimal());
+        assert!(Alphabet::Z85.is_decimal());
+        assert!(Alphabet::from_digits(b"0123456789").is_decimal());
+        assert!(!Alphabet::from_digits(b"01234567").is_decimal());
+        assert!(!Alphabet::BASE58.is_decimal());
+        assert!(!Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789").is_decimal());
+        assert!(Alphabet::new(b"0123456789zyxwvutsrqponmlkjihgfedcba").is_decimal());
+    }
+
+    #[test]
+    fn hex_detection() {
+        assert!(Alphabet::UPPER.hex_digits() == HexDigits::Upper);
+        assert!(Alphabet::LOWER.hex_digits() == HexDigits::Lower);
+        assert!(Alphabet::BASE62.hex_digits() == HexDigits::Upper);
+        assert!(Alphabet::Z85.hex_digits() == HexDigits::Lower);
+        assert!(Alphabet::from_digits(b"0123456789abcdef").hex_digits() == HexDigits::Lower);
+        assert!(Alphabet::from_digits(b"0123456789abcde").hex_digits() == HexDigits::Other);
+        assert!(Alphabet::BASE58.hex_digits() == HexDigits::Other);
+        assert!(
+            Alphabet::new(b"0123456789AbCDEFGHIJKLMNOPQRSTUVWXYZ").hex_digits() == HexDigits::Other
+        );
+    }
+
+    #[test]
+    fn upper_digits() {
+        assert_eq!(36, Alphabet::UPPER.upper_digits());
+        assert_eq!(10, Alphabet::LOWER.upper_digits());
+        assert_eq!(36, Alphabet::BASE62.upper_digits());
+        assert_eq!(0, Alphabet::BASE58.upper_digits());
+        assert_eq!(12, Alphabet::from_digits(b"0123456789AB").upper_digits());
+    }
+
+    #[test]
+    fn const_construction() {
+        const DIGITS: &[u8; 36] = b"zyxwvutsrqponmlkjihgfedcba9876543210";
+        const REVERSED: Alphabet = Alphabet::new(DIGITS);
+        assert_eq!(DIGITS, REVERSED.as_bytes());
+        assert_eq!(b'z', REVERSED.digit(0));
+        assert_eq!(b'0', REVERSED.digit(35));
+    }
+
+    #[test]
+    fn standard_alphabet_sizes() {
+        assert_eq!(36, Alphabet::UPPER.max_base());
+        assert_eq!(36, Alphabet::LOWER.max_base());
+        assert_eq!(58, Alphabet::BASE58.max_base());
+        assert_eq!(62, Alphabet::BASE62.max_base());
+        assert_eq!(64, Alphabet::BASE64URL.max_base());
+        assert_eq!(85, Alphabet::Z85.max_base());
+        assert_eq!(85, Alphabet::ASCII85.max_base());
+        assert_eq!(b'!', Alphabet::ASCII85.digit(0));
+        assert_eq!(b'u', Alphabet::ASCII85.digit(84));
+    }
+
+    #[test]
+    fn standard_alphabets_are_unique() {
+        for alphabet in [
+            Alphabet::UPPER,
+            Alphabet::LOWER,
+            Alphabet::BASE58,
+            Alphabet::BASE62,
+            Alphabet::BASE64URL,
+            Alphabet::Z85,
+            Alphabet::ASCII85,
+        ] {
+            let digits = alphabet.as_bytes();
+            for (index, digit) in digits.iter().enumerate() {
+                assert!(!digits[index + 1..].contains(digit));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "alphabet digits must be ASCII")]
+    fn non_ascii_digits() {
+        let mut digits = *UPPER_DIGITS;
+        digits[35] = 0xFF;
+        let _ = Alphabet::new(&digits);
+    }
+
+    #[test]
+    #[should_panic(expected = "alphabet digits must be distinct")]
+    fn repeated_digits() {
+        let _ = Alphabet::from_digits(b"0123456789012345");
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_digits() {
+        let _ = Alphabet::from_digits(b"0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_digits() {
+        let _ = Alphabet::from_digits(&[b'0'; 86]);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::numtoa_core::required_space;
+use crate::numtoa_float::REQUIRED_SPACE_F64;
+use crate::numtoa_radix::Radix;
+use crate::numtoa_trait::NumToA;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The storage of a [Buffer], which is implemented for every base from 2 to 36.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait BufferSize: private::Sealed {
+    /// A byte array large enough to hold any integer in this base.
+    type Bytes: Copy + AsMut<[u8]>;
+
+    /// The storage of a new buffer.
+    const ZEROED: Self::Bytes;
+}
+
+/// A number which can be written into a [Buffer] in the given base, which is any integer, or a float in base 10.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait BufferFormat<const BASE: u32>: NumToA + private::Sealed {}
+
+/// A reusable buffer which is large enough to hold any integer in the given base, defaulting to base 10. Without the
+/// `u128` feature, buffers are only sized for 64 bit integers.
+///
+/// The size of the buffer is known at compile time, so conversions into it can never run out of space.
+///
+/// # Example
+/// ```
+/// use numtoa::Buffer;
+///
+/// let mut buffer = Buffer::<10>::new();
+/// assert_eq!(buffer.format_str(i64::MIN), "-9223372036854775808");
+///
+/// let mut buffer = Buffer::<16>::new();
+/// assert_eq!(buffer.format_str(u64::MAX), "FFFFFFFFFFFFFFFF");
+/// ```
+///
+/// Floats can only be written in base 10, and any other base fails to compile.
+///
+/// ```compile_fail
+/// use numtoa::Buffer;
+///
+/// let _ = Buffer::<16>::new().format(1.0f32);
+/// ```
+pub struct Buffer<const BASE: u32 = 10>
+where
+    Buffer<BASE>: BufferSize,
+{
+    bytes: <Buffer<BASE> as BufferSize>::Bytes,
+}
+
+impl<const BASE: u32> Buffer<BASE>
+where
+    Buffer<BASE>: BufferSize,
+{
+    /// Creates a new buffer.
+    pub const fn new() -> Buffer<BASE> {
+        Buffer {
+            bytes: <Buffer<BASE> as BufferSize>::ZEROED,
+        }
+    }
+
+    /// Writes the number into the buffer, returning the bytes that were written.
+    pub fn format<N: BufferFormat<BASE>>(&mut self, num: N) -> &[u8] {
+        num.numtoa_radix(Radix::new(BASE), self.bytes.as_mut())
+    }
+
+    /// Convenience method for quickly getting a string from the buffer.
+    pub fn format_str<N: BufferFormat<BASE>>(&mut self, num: N) -> &str {
+        num.numtoa_radix_str(Radix::new(BASE), self.bytes.as_mut())
+    }
+}
+
+impl<const BASE: u32> Default for Buffer<BASE>
+where
+    Buffer<BASE>: BufferSize,
+{
+    fn default() -> Buffer<BASE> {
+        Buffer::new()
+    }
+}
+
+impl<const BASE: u32> Clone for Buffer<BASE>
+where
+    Buffer<BASE>: BufferSize,
+{
+    fn clone(&self) -> Buffer<BASE> {
+        *self
+    }
+}
+
+impl<const BASE: u32> Copy for Buffer<BASE> where Buffer<BASE>: BufferSize {}
+
+// The most negative integer of the widest supported type needs the most space in every base.
+#[cfg(feature = "u128")]
+const LARGEST_MAGNITUDE: u128 = i128::MIN.unsigned_abs();
+#[cfg(not(feature = "u128"))]
+const LARGEST_MAGNITUDE: u128 = i64::MIN.unsigned_abs() as u128;
+
+// The size of a buffer in the given base, which in base 10 must also hold any float.
+const fn buffer_size(base: u32) -> usize {
+    let integers = required_space(base as u128, LARGEST_MAGNITUDE, true);
+    if base == 10 && integers < REQUIRED_SPACE_F64 {
+        REQUIRED_SPACE_F64
+    } else {
+        integers
+    }
+}
+
+macro_rules! impl_buffer_size {
+    ($($base:expr),*) => {
+        $(
+            impl private::Sealed for Buffer<$base> {}
+
+            impl BufferSize for Buffer<$base> {
+                type Bytes = [u8; buffer_size($base)];
+
+                const ZEROED: Self::Bytes = [0; buffer_size($base)];
+            }
+        )*
+    };
+}
+
+impl_buffer_size!(
+    2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+    28, 29, 30, 31, 32, 33, 34, 35, 36
+);
+
+macro_rules! impl_buffer_format {
+    ($($type_name:ty),*) => {
+        $(
+            impl private::Sealed for $type_name {}
+
+            impl<const BASE: u32> BufferFormat<BASE> for $type_name {}
+        )*
+    };
+}
+
+impl_buffer_format!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+#[cfg(feature = "u128")]
+impl_buffer_format!(i128, u128);
+
+impl private::Sealed for f32 {}
+impl BufferFormat<10> for f32 {}
+
+impl private::Sealed for f64 {}
+impl BufferFormat<10> for f64 {}
+
+#[cfg(test)]
+mod buffer_test {
+    use super::*;
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn sizes() {
+        assert_eq!(core::mem::size_of::<Buffer>(), 40);
+        assert_eq!(core::mem::size_of::<Buffer<2>>(), 129);
+        assert_eq!(core::mem::size_of::<Buffer<16>>(), 33);
+        assert_eq!(core::mem::size_of::<Buffer<36>>(), 26);
+    }
+
+    #[cfg(not(feature = "u128"))]
+    #[test]
+    fn sizes_without_u128() {
+        assert_eq!(core::mem::size_of::<Buffer>(), REQUIRED_SPACE_F64);
+        assert_eq!(core::mem::size_of::<Buffer<2>>(), 65);
+        assert_eq!(core::mem::size_of::<Buffer<16>>(), 17);
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn largest_integers() {
+        let mut buffer = Buffer::<2>::new();
+        assert_eq!(buffer.format(u128::MAX), [b'1'; 128]);
+        assert_eq!(buffer.format(i128::MIN).len(), 129);
+        assert_eq!(buffer.format_str(-5i8), "-101");
+
+        let mut buffer = Buffer::<36>::default();
+        assert_eq!(buffer.format_str(35u8), "Z");
+        assert_eq!(buffer.format_str(i64::MIN), "-1Y2P0IJ32E8E8");
+    }
+
+    #[test]
+    fn reuse() {
+        let mut buffer: Buffer = Buffer::new();
+        assert_eq!(buffer.format_str(u64::MAX), "18446744073709551615");
+        assert_eq!(buffer.format_str(7u8), "7");
+        assert_eq!(buffer.format_str(-2.5f64), "-2.5");
+    }
+}
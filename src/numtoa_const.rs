@@ -3,9 +3,14 @@ use core::{
     ops::Deref,
 };
 
+use crate::numtoa_alphabet::Alphabet;
 use crate::numtoa_core::*;
+use crate::numtoa_radix::Radix;
 
 /// API to convert numbers into ascii string in base N. Infallible & const-friendly. Returns an [AsciiNumber] of fixed size based on the selected base and numeric type.
+///
+/// Bases 2 through 36 use the digits of [Alphabet::UPPER] by default. Bases 58, 62, 64 and 85 are also available,
+/// using the digits of [Alphabet::BASE58], [Alphabet::BASE62], [Alphabet::BASE64URL] and [Alphabet::Z85].
 pub struct BaseN<const N: usize> {}
 
 /// The immutable result of a [BaseN] number conversion to ascii, containing a string containing at most N bytes / N ascii characters.
@@ -82,6 +87,70 @@ impl<const N: usize> Debug for AsciiNumber<N> {
     }
 }
 
+macro_rules! impl_ascii_number_from {
+    ($($type_name:ty, $base_type_name:ty, $write_function_name:ident, $from_function_name:ident, $required_space_constant_name:ident);*) => {
+        $(
+            impl AsciiNumber<{ BaseN::<2>::$required_space_constant_name }> {
+                #[doc = concat!("Converts the specified [", stringify!($type_name), "] to its ASCII representation in a base only known at runtime, into an [AsciiNumber] large enough for any base")]
+                pub const fn $from_function_name(num: $type_name, radix: Radix) -> Self {
+                    let mut string = [0_u8; BaseN::<2>::$required_space_constant_name];
+                    let start = BaseN::<2>::$required_space_constant_name
+                        - $write_function_name(num, radix.get() as $base_type_name, &Alphabet::UPPER, as_uninit_mut(&mut string)).len();
+                    AsciiNumber { string, start }
+                }
+            }
+        )*
+    };
+}
+
+impl_ascii_number_from!(
+    u8, u8, write_u8, from_u8, REQUIRED_SPACE_U8;
+    u16, u16, write_u16, from_u16, REQUIRED_SPACE_U16;
+    u32, u32, write_u32, from_u32, REQUIRED_SPACE_U32;
+    u64, u64, write_u64, from_u64, REQUIRED_SPACE_U64;
+    usize, usize, write_usize, from_usize, REQUIRED_SPACE_USIZE;
+    i8, u8, write_i8, from_i8, REQUIRED_SPACE_I8;
+    i16, u16, write_i16, from_i16, REQUIRED_SPACE_I16;
+    i32, u32, write_i32, from_i32, REQUIRED_SPACE_I32;
+    i64, u64, write_i64, from_i64, REQUIRED_SPACE_I64;
+    isize, usize, write_isize, from_isize, REQUIRED_SPACE_ISIZE
+);
+#[cfg(feature = "u128")]
+impl_ascii_number_from!(
+    u128, u128, write_u128, from_u128, REQUIRED_SPACE_U128;
+    i128, u128, write_i128, from_i128, REQUIRED_SPACE_I128
+);
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The conversion of an integer type into an [AsciiNumber] in the base of a [BaseN], which is implemented for every
+/// integer type and base. This allows [NumToA::to_ascii](crate::NumToA::to_ascii) to be called from generic code.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait AsciiConversion<T>: private::Sealed {
+    /// An [AsciiNumber] large enough to hold any value of the type in this base.
+    type Output: Copy + Eq + Default + Debug + Display + Deref<Target = str>;
+
+    /// Converts the number, equivalent to the function of [BaseN] named after its type.
+    fn convert(num: T) -> Self::Output;
+}
+
+macro_rules! impl_ascii_conversion_for_base_n {
+    ($base_value:expr; $($type_name:ty, $base_n_function_name:ident, $required_space_constant_name:ident);*) => {
+        $(
+            impl AsciiConversion<$type_name> for BaseN<$base_value> {
+                type Output = AsciiNumber<{ BaseN::<$base_value>::$required_space_constant_name }>;
+
+                fn convert(num: $type_name) -> Self::Output {
+                    BaseN::<$base_value>::$base_n_function_name(num)
+                }
+            }
+        )*
+    };
+}
+
 const fn const_max(a: usize, b: usize) -> usize {
     if a > b {
         a
@@ -94,8 +163,11 @@ macro_rules! impl_numtoa_const_for_base_on_type {
     (
     $type_name:ty,
     $base:expr,
-    $core_function_name:ident,
+    $write_function_name:ident,
+    $write_pairs_function_name:ident,
+    $base_type_name:ty,
     $base_n_function_name:ident,
+    $alphabet_base_n_function_name:ident,
     $padded_function_name:ident,
     $filled_function_name:ident,
     $required_space_constant_name:ident,
@@ -110,7 +182,19 @@ macro_rules! impl_numtoa_const_for_base_on_type {
         ) -> AsciiNumber<{ Self::$required_space_constant_name }> {
             let mut string = [0_u8; Self::$required_space_constant_name];
             let start = Self::$required_space_constant_name
-                - $core_function_name(num, $base, &mut string).len();
+                - unsafe { $write_pairs_function_name::<false>(num, $base as $base_type_name, &Self::ALPHABET, Self::PAIRS, as_uninit_mut(&mut string)) }.len();
+            return AsciiNumber { string, start };
+        }
+
+        #[doc = concat!("converts the specified [", stringify!($type_name), "] to its ASCII representation in base ", $base, ", using the digits of the provided [Alphabet]")]
+        pub const fn $alphabet_base_n_function_name(
+            num: $type_name,
+            alphabet: &Alphabet,
+        ) -> AsciiNumber<{ Self::$required_space_constant_name }> {
+            assert!(alphabet.max_base() >= $base, "unsupported base");
+            let mut string = [0_u8; Self::$required_space_constant_name];
+            let start = Self::$required_space_constant_name
+                - $write_function_name(num, $base as $base_type_name, alphabet, as_uninit_mut(&mut string)).len();
             return AsciiNumber { string, start };
         }
 
@@ -121,7 +205,7 @@ macro_rules! impl_numtoa_const_for_base_on_type {
         ) -> AsciiNumber<LENGTH> {
             const { assert!(LENGTH >= { Self::$required_space_constant_name }) }
             let mut string = [padding; LENGTH];
-            let _ = $core_function_name(num, $base, &mut string);
+            let _ = unsafe { $write_pairs_function_name::<false>(num, $base as $base_type_name, &Self::ALPHABET, Self::PAIRS, as_uninit_mut(&mut string)) };
             return AsciiNumber { string, start: 0 };
         }
 
@@ -133,7 +217,7 @@ macro_rules! impl_numtoa_const_for_base_on_type {
             const { assert!(LENGTH <= { Self::$required_space_constant_name }) }
             let mut string = [fill; Self::$required_space_constant_name];
             let start = Self::$required_space_constant_name
-                - const_max(LENGTH, $core_function_name(num, $base, &mut string).len());
+                - const_max(LENGTH, unsafe { $write_pairs_function_name::<false>(num, $base as $base_type_name, &Self::ALPHABET, Self::PAIRS, as_uninit_mut(&mut string)) }.len());
             return AsciiNumber { string, start };
         }
     };
@@ -141,12 +225,33 @@ macro_rules! impl_numtoa_const_for_base_on_type {
 
 macro_rules! impl_numtoa_const_for_base_n {
     ($base_value:expr) => {
+        impl_numtoa_const_for_base_n!($base_value, Alphabet::UPPER);
+    };
+    ($base_value:expr, $alphabet:expr) => {
         impl BaseN<$base_value> {
+            // The digits used when no alphabet is specified.
+            const ALPHABET: Alphabet = $alphabet;
+
+            // Every pair of digits of the default alphabet, which is only embedded by the conversions that are used.
+            // Powers of two and base 10 have their own algorithms.
+            #[cfg(not(feature = "small"))]
+            const PAIRS: &'static [u8] =
+                if ($base_value as u32).is_power_of_two() || $base_value == 10 {
+                    &[]
+                } else {
+                    &pair_lookup::<{ 2 * $base_value * $base_value }>($base_value, &Self::ALPHABET)
+                };
+            #[cfg(feature = "small")]
+            const PAIRS: &'static [u8] = &[];
+
             impl_numtoa_const_for_base_on_type!(
                 u8,
                 $base_value,
-                numtoa_u8,
+                write_u8,
+                write_u8_pairs,
+                u8,
                 u8,
+                u8_with_alphabet,
                 u8_padded,
                 u8_filled,
                 REQUIRED_SPACE_U8,
@@ -155,8 +260,11 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 u16,
                 $base_value,
-                numtoa_u16,
+                write_u16,
+                write_u16_pairs,
                 u16,
+                u16,
+                u16_with_alphabet,
                 u16_padded,
                 u16_filled,
                 REQUIRED_SPACE_U16,
@@ -165,8 +273,11 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 u32,
                 $base_value,
-                numtoa_u32,
+                write_u32,
+                write_u32_pairs,
+                u32,
                 u32,
+                u32_with_alphabet,
                 u32_padded,
                 u32_filled,
                 REQUIRED_SPACE_U32,
@@ -175,18 +286,25 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 u64,
                 $base_value,
-                numtoa_u64,
+                write_u64,
+                write_u64_pairs,
                 u64,
+                u64,
+                u64_with_alphabet,
                 u64_padded,
                 u64_filled,
                 REQUIRED_SPACE_U64,
                 required_space($base_value as u128, u64::MAX as u128, false)
             );
+            #[cfg(feature = "u128")]
             impl_numtoa_const_for_base_on_type!(
                 u128,
                 $base_value,
-                numtoa_u128,
+                write_u128,
+                write_u128_pairs,
+                u128,
                 u128,
+                u128_with_alphabet,
                 u128_padded,
                 u128_filled,
                 REQUIRED_SPACE_U128,
@@ -195,8 +313,11 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 usize,
                 $base_value,
-                numtoa_usize,
+                write_usize,
+                write_usize_pairs,
                 usize,
+                usize,
+                usize_with_alphabet,
                 usize_padded,
                 usize_filled,
                 REQUIRED_SPACE_USIZE,
@@ -205,8 +326,11 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 i8,
                 $base_value,
-                numtoa_i8,
+                write_i8,
+                write_i8_pairs,
+                u8,
                 i8,
+                i8_with_alphabet,
                 i8_padded,
                 i8_filled,
                 REQUIRED_SPACE_I8,
@@ -215,8 +339,11 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 i16,
                 $base_value,
-                numtoa_i16,
+                write_i16,
+                write_i16_pairs,
+                u16,
                 i16,
+                i16_with_alphabet,
                 i16_padded,
                 i16_filled,
                 REQUIRED_SPACE_I16,
@@ -225,8 +352,11 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 i32,
                 $base_value,
-                numtoa_i32,
+                write_i32,
+                write_i32_pairs,
+                u32,
                 i32,
+                i32_with_alphabet,
                 i32_padded,
                 i32_filled,
                 REQUIRED_SPACE_I32,
@@ -235,18 +365,25 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 i64,
                 $base_value,
-                numtoa_i64,
+                write_i64,
+                write_i64_pairs,
+                u64,
                 i64,
+                i64_with_alphabet,
                 i64_padded,
                 i64_filled,
                 REQUIRED_SPACE_I64,
                 required_space($base_value as u128, i64::MIN.unsigned_abs() as u128, true)
             );
+            #[cfg(feature = "u128")]
             impl_numtoa_const_for_base_on_type!(
                 i128,
                 $base_value,
-                numtoa_i128,
+                write_i128,
+                write_i128_pairs,
+                u128,
                 i128,
+                i128_with_alphabet,
                 i128_padded,
                 i128_filled,
                 REQUIRED_SPACE_I128,
@@ -255,14 +392,39 @@ macro_rules! impl_numtoa_const_for_base_n {
             impl_numtoa_const_for_base_on_type!(
                 isize,
                 $base_value,
-                numtoa_isize,
+                write_isize,
+                write_isize_pairs,
+                usize,
                 isize,
+                isize_with_alphabet,
                 isize_padded,
                 isize_filled,
                 REQUIRED_SPACE_ISIZE,
                 required_space($base_value as u128, isize::MIN.unsigned_abs() as u128, true)
             );
         }
+
+        impl private::Sealed for BaseN<$base_value> {}
+
+        impl_ascii_conversion_for_base_n!(
+            $base_value;
+            u8, u8, REQUIRED_SPACE_U8;
+            u16, u16, REQUIRED_SPACE_U16;
+            u32, u32, REQUIRED_SPACE_U32;
+            u64, u64, REQUIRED_SPACE_U64;
+            usize, usize, REQUIRED_SPACE_USIZE;
+            i8, i8, REQUIRED_SPACE_I8;
+            i16, i16, REQUIRED_SPACE_I16;
+            i32, i32, REQUIRED_SPACE_I32;
+            i64, i64, REQUIRED_SPACE_I64;
+            isize, isize, REQUIRED_SPACE_ISIZE
+        );
+        #[cfg(feature = "u128")]
+        impl_ascii_conversion_for_base_n!(
+            $base_value;
+            u128, u128, REQUIRED_SPACE_U128;
+            i128, i128, REQUIRED_SPACE_I128
+        );
     };
 }
 
@@ -281,6 +443,30 @@ impl_numtoa_const_for_base_n!(13);
 impl_numtoa_const_for_base_n!(14);
 impl_numtoa_const_for_base_n!(15);
 impl_numtoa_const_for_base_n!(16);
+impl_numtoa_const_for_base_n!(17);
+impl_numtoa_const_for_base_n!(18);
+impl_numtoa_const_for_base_n!(19);
+impl_numtoa_const_for_base_n!(20);
+impl_numtoa_const_for_base_n!(21);
+impl_numtoa_const_for_base_n!(22);
+impl_numtoa_const_for_base_n!(23);
+impl_numtoa_const_for_base_n!(24);
+impl_numtoa_const_for_base_n!(25);
+impl_numtoa_const_for_base_n!(26);
+impl_numtoa_const_for_base_n!(27);
+impl_numtoa_const_for_base_n!(28);
+impl_numtoa_const_for_base_n!(29);
+impl_numtoa_const_for_base_n!(30);
+impl_numtoa_const_for_base_n!(31);
+impl_numtoa_const_for_base_n!(32);
+impl_numtoa_const_for_base_n!(33);
+impl_numtoa_const_for_base_n!(34);
+impl_numtoa_const_for_base_n!(35);
+impl_numtoa_const_for_base_n!(36);
+impl_numtoa_const_for_base_n!(58, Alphabet::BASE58);
+impl_numtoa_const_for_base_n!(62, Alphabet::BASE62);
+impl_numtoa_const_for_base_n!(64, Alphabet::BASE64URL);
+impl_numtoa_const_for_base_n!(85, Alphabet::Z85);
 
 #[test]
 fn str_convenience_base2() {
@@ -338,6 +524,144 @@ fn str_convenience_base16_filled() {
     );
 }
 
+#[test]
+fn str_convenience_base16_lowercase() {
+    assert_eq!(
+        "3e87b",
+        BaseN::<16>::i32_with_alphabet(256123, &Alphabet::LOWER).as_str()
+    );
+    assert_eq!(
+        "-3e87b",
+        BaseN::<16>::i32_with_alphabet(-256123, &Alphabet::LOWER).as_str()
+    );
+    assert_eq!(
+        "ffffffffffffffff",
+        BaseN::<16>::u64_with_alphabet(u64::MAX, &Alphabet::LOWER).as_str()
+    );
+}
+
+#[test]
+fn str_convenience_custom_alphabet() {
+    const LETTERS: Alphabet = Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789");
+    const CONVERTED: AsciiNumber<5> = BaseN::<10>::u16_with_alphabet(40213, &LETTERS);
+    assert_eq!("EACBD", CONVERTED.as_str());
+    assert_eq!("A", BaseN::<10>::u8_with_alphabet(0, &LETTERS).as_str());
+}
+
+#[test]
+fn str_convenience_base17() {
+    assert_eq!("51G2A21", BaseN::<17>::u32(123456789).as_str());
+}
+
+#[test]
+fn str_convenience_base32() {
+    assert_eq!("-8000000000000", BaseN::<32>::i64(i64::MIN).as_str());
+    assert_eq!("FVVVVVVVVVVVV", BaseN::<32>::u64(u64::MAX).as_str());
+    assert_eq!(
+        "000FVVVVVVVVVVVV",
+        BaseN::<32>::u64_padded::<16>(u64::MAX, b'0').as_str()
+    );
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn str_convenience_base36() {
+    assert_eq!("3W5E11264SGSF", BaseN::<36>::u64(u64::MAX).as_str());
+    assert_eq!(
+        "F5LXX1ZZ5PNORYNQGLHZMSP33",
+        BaseN::<36>::u128(u128::MAX).as_str()
+    );
+    assert_eq!("00Z", BaseN::<36>::u16_filled::<3>(35, b'0').as_str());
+    assert_eq!(
+        "3w5e11264sgsf",
+        BaseN::<36>::u64_with_alphabet(u64::MAX, &Alphabet::LOWER).as_str()
+    );
+}
+
+#[test]
+fn str_convenience_base58() {
+    assert_eq!("jpXCZedGfVQ", BaseN::<58>::u64(u64::MAX).as_str());
+    assert_eq!("-NQm6nKp8qFD", BaseN::<58>::i64(i64::MIN).as_str());
+    assert_eq!("2t6V2H", BaseN::<58>::u32(1234567890).as_str());
+    assert_eq!("1", BaseN::<58>::u8(0).as_str());
+    assert_eq!(
+        "11111111111jpXCZedGfVQ",
+        BaseN::<58>::u64_padded::<22>(u64::MAX, b'1').as_str()
+    );
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn str_convenience_base62() {
+    assert_eq!("LygHa16AHYF", BaseN::<62>::u64(u64::MAX).as_str());
+    assert_eq!(
+        "7n42DGM5Tflk9n8mt7Fhc7",
+        BaseN::<62>::u128(u128::MAX).as_str()
+    );
+    assert_eq!("47", BaseN::<62>::u8(255).as_str());
+}
+
+#[test]
+fn str_convenience_base64() {
+    assert_eq!("P__________", BaseN::<64>::u64(u64::MAX).as_str());
+    assert_eq!("-IAAAAAAAAAA", BaseN::<64>::i64(i64::MIN).as_str());
+    assert_eq!("BJlgLS", BaseN::<64>::u32(1234567890).as_str());
+    assert_eq!("AAD_", BaseN::<64>::u32_filled::<4>(255, b'A').as_str());
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn str_convenience_base85() {
+    assert_eq!("{SW2>[/%o0", BaseN::<85>::u64(u64::MAX).as_str());
+    assert_eq!(
+        ">R54LJ=nuuo#hI+C2YM0",
+        BaseN::<85>::u128(u128::MAX).as_str()
+    );
+    assert_eq!(
+        "pW[#knfs9!",
+        BaseN::<85>::u64_with_alphabet(u64::MAX, &Alphabet::ASCII85).as_str()
+    );
+    assert_eq!(
+        "8X9G+",
+        BaseN::<85>::u32_with_alphabet(1234567890, &Alphabet::ASCII85).as_str()
+    );
+}
+
+#[test]
+#[should_panic]
+fn base_n_alphabet_too_small() {
+    let _ = BaseN::<58>::u64_with_alphabet(0, &Alphabet::UPPER);
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn required_space_beyond_base36() {
+    assert_eq!(11, BaseN::<58>::REQUIRED_SPACE_U64);
+    assert_eq!(12, BaseN::<58>::REQUIRED_SPACE_I64);
+    assert_eq!(22, BaseN::<62>::REQUIRED_SPACE_U128);
+    assert_eq!(23, BaseN::<62>::REQUIRED_SPACE_I128);
+    assert_eq!(6, BaseN::<64>::REQUIRED_SPACE_U32);
+    assert_eq!(2, BaseN::<85>::REQUIRED_SPACE_U8);
+    assert_eq!(5, BaseN::<85>::REQUIRED_SPACE_U32);
+    assert_eq!(10, BaseN::<85>::REQUIRED_SPACE_U64);
+    assert_eq!(20, BaseN::<85>::REQUIRED_SPACE_U128);
+    assert_eq!(21, BaseN::<85>::REQUIRED_SPACE_I128);
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn required_space_base17_to_base36() {
+    assert_eq!(2, BaseN::<17>::REQUIRED_SPACE_U8);
+    assert_eq!(16, BaseN::<17>::REQUIRED_SPACE_U64);
+    assert_eq!(13, BaseN::<32>::REQUIRED_SPACE_U64);
+    assert_eq!(14, BaseN::<32>::REQUIRED_SPACE_I64);
+    assert_eq!(2, BaseN::<36>::REQUIRED_SPACE_U8);
+    assert_eq!(3, BaseN::<36>::REQUIRED_SPACE_I8);
+    assert_eq!(13, BaseN::<36>::REQUIRED_SPACE_U64);
+    assert_eq!(25, BaseN::<36>::REQUIRED_SPACE_U128);
+    assert_eq!(26, BaseN::<36>::REQUIRED_SPACE_I128);
+}
+
 #[test]
 fn str_convenience_wacky_padding() {
     assert_eq!(
@@ -374,6 +698,25 @@ fn base16_i8_all_base16() {
     }
 }
 
+#[test]
+fn base36_i8_all_base36() {
+    let mut buffer = [0u8; 3];
+    for i in i8::MIN..=i8::MAX {
+        assert_eq!(numtoa_i8(i, 36, &mut buffer), BaseN::<36>::i8(i).as_bytes());
+    }
+}
+
+#[test]
+fn base85_i8_all_base85() {
+    let mut buffer = [0u8; 3];
+    for i in i8::MIN..=i8::MAX {
+        assert_eq!(
+            numtoa_i8_in_alphabet(i, &Alphabet::Z85, &mut buffer),
+            BaseN::<85>::i8(i).as_bytes()
+        );
+    }
+}
+
 #[test]
 fn base10_u8_all_base10() {
     for i in u8::MIN..u8::MAX {
@@ -387,3 +730,52 @@ fn base16_u8_all_base16() {
         let _ = BaseN::<16>::u8(i);
     }
 }
+
+#[test]
+fn ascii_number_from_runtime_radix() {
+    let mut buffer = [0u8; 130];
+    for base in 2..=36 {
+        let radix = Radix::new(base);
+        for value in [0, 1, -1, i64::MIN, i64::MAX, 256123, -256123] {
+            let ascii = AsciiNumber::from_i64(value, radix);
+            assert_eq!(
+                ascii.as_str(),
+                numtoa_i64_str(value, base as i64, &mut buffer)
+            );
+            let ascii = AsciiNumber::from_u64(value as u64, radix);
+            assert_eq!(
+                ascii.as_str(),
+                numtoa_u64_str(value as u64, base as u64, &mut buffer)
+            );
+        }
+        assert_eq!(
+            AsciiNumber::from_i8(i8::MIN, radix).as_str(),
+            numtoa_i8_str(i8::MIN, base as i8, &mut buffer)
+        );
+        assert_eq!(
+            AsciiNumber::from_u16(u16::MAX, radix).as_str(),
+            numtoa_u16_str(u16::MAX, base as u16, &mut buffer)
+        );
+        #[cfg(feature = "u128")]
+        assert_eq!(
+            AsciiNumber::from_i128(i128::MIN, radix).as_str(),
+            numtoa_i128_str(i128::MIN, base as i128, &mut buffer)
+        );
+    }
+}
+
+#[test]
+fn ascii_number_from_is_sized_for_base2() {
+    let ascii = AsciiNumber::from_i32(i32::MIN, Radix::BIN);
+    let copy = ascii;
+    assert_eq!(copy, ascii);
+    assert_eq!(ascii.as_str().len(), BaseN::<2>::REQUIRED_SPACE_I32);
+    assert_eq!(
+        core::mem::size_of_val(&AsciiNumber::from_u8(0, Radix::DEC).into_inner().0),
+        BaseN::<2>::REQUIRED_SPACE_U8
+    );
+
+    const HEX: AsciiNumber<{ BaseN::<2>::REQUIRED_SPACE_USIZE }> =
+        AsciiNumber::from_usize(255, Radix::HEX);
+    assert_eq!(HEX.as_str(), "FF");
+}
@@ -1,42 +1,313 @@
+use core::mem::MaybeUninit;
+
+use crate::numtoa_alphabet::Alphabet;
+#[cfg(not(feature = "small"))]
+use crate::numtoa_alphabet::HexDigits;
+use crate::numtoa_error::NumToAError;
+use crate::numtoa_radix::Radix;
+
 /// returns the number of bytes required for a base N number conversion
 pub const fn required_space(base: u128, number: u128, negative: bool) -> usize {
     if base == 0 {
         return 0;
     }
+    if base >= 2 && base <= u32::MAX as u128 {
+        return count_digits_u128(number, base as u32) + (negative as usize);
+    }
     if number == 0 {
         return 1 + (negative as usize);
     }
     let mut number_bytes_required = 0;
     let mut n = number;
     while n > 0 {
-        n /= base as u128;
+        n /= base;
         number_bytes_required += 1;
     }
     number_bytes_required + (negative as usize)
 }
 
+// Views an initialized buffer as a possibly uninitialized one, which is sound because only initialized bytes are
+// ever written through it.
+pub(crate) const fn as_uninit_mut(string: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    unsafe { &mut *(string as *mut [u8] as *mut [MaybeUninit<u8>]) }
+}
+
+// Views the bytes of a buffer as initialized.
+//
+// # Safety
+// Every byte of `string` must have been written.
+pub(crate) const unsafe fn assume_init(string: &[MaybeUninit<u8>]) -> &[u8] {
+    &*(string as *const [MaybeUninit<u8>] as *const [u8])
+}
+
+// Stores a byte into the buffer of a write function. Where the function's `UNCHECKED` parameter is set, there is no
+// bounds check, and the caller must have guaranteed that the buffer is large enough.
+macro_rules! put {
+    ($string:ident, $index:expr, $value:expr) => {
+        if UNCHECKED {
+            unsafe { *$string.as_mut_ptr().add($index) = MaybeUninit::new($value) }
+        } else {
+            $string[$index] = MaybeUninit::new($value);
+        }
+    };
+}
+
+// The bytes of the buffer of a write function from `index` onwards, which must all have been written, with a bounds
+// check unless `UNCHECKED` is set.
+macro_rules! written {
+    ($string:ident, $index:expr) => {
+        if UNCHECKED {
+            unsafe {
+                core::slice::from_raw_parts(
+                    $string.as_ptr().add($index) as *const u8,
+                    $string.len() - $index,
+                )
+            }
+        } else {
+            unsafe { assume_init($string.split_at($index).1) }
+        }
+    };
+}
+
 // A lookup table to prevent the need for conditional branching
 // The value of the remainder of each step will be used as the index
+#[cfg_attr(any(feature = "small", feature = "fast"), allow(dead_code))]
 const LOOKUP: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 // A lookup table optimized for decimal lookups. Each two indices represents one possible number.
+#[cfg_attr(any(feature = "small", feature = "fast"), allow(dead_code))]
 const DEC_LOOKUP: &[u8; 200] = b"0001020304050607080910111213141516171819\
                                  2021222324252627282930313233343536373839\
                                  4041424344454647484950515253545556575859\
                                  6061626364656667686970717273747576777879\
                                  8081828384858687888990919293949596979899";
 
-// The maximum supported base given the standard alphabet
-const MAX_SUPPORTED_BASE: u128 = LOOKUP.len() as u128;
+// Builds a lookup table of every number below 10^4 as four decimal digits.
+#[cfg_attr(not(all(feature = "fast", not(feature = "small"))), allow(dead_code))]
+const fn quad_lookup() -> [u8; 40000] {
+    let mut table = [0u8; 40000];
+    let mut index = 0;
+    while index < 10000 {
+        table[index * 4] = b'0' + (index / 1000) as u8;
+        table[index * 4 + 1] = b'0' + (index / 100 % 10) as u8;
+        table[index * 4 + 2] = b'0' + (index / 10 % 10) as u8;
+        table[index * 4 + 3] = b'0' + (index % 10) as u8;
+        index += 1;
+    }
+    table
+}
+
+// The lookup table of the `fast` feature, which writes four decimal digits at a time.
+#[cfg_attr(not(all(feature = "fast", not(feature = "small"))), allow(dead_code))]
+const DEC_QUAD_LOOKUP: &[u8; 40000] = &quad_lookup();
+
+// Builds a lookup table of every number below `base * base` as two digits of the alphabet, in the same layout as
+// `DEC_LOOKUP`.
+#[cfg_attr(feature = "small", allow(dead_code))]
+pub(crate) const fn pair_lookup<const LEN: usize>(base: usize, alphabet: &Alphabet) -> [u8; LEN] {
+    assert!(
+        LEN == 2 * base * base,
+        "pair lookup tables hold two digits for every pair"
+    );
+    let mut table = [0u8; LEN];
+    let mut index = 0;
+    while index < base * base {
+        table[index * 2] = alphabet.digit(index / base);
+        table[index * 2 + 1] = alphabet.digit(index % base);
+        index += 1;
+    }
+    table
+}
+
+// Lookup tables for hexadecimal, converting a whole byte at a time.
+#[cfg(not(feature = "small"))]
+const HEX_UPPER_LOOKUP: &[u8; 512] = &pair_lookup(16, &Alphabet::UPPER);
+#[cfg(not(feature = "small"))]
+const HEX_LOWER_LOOKUP: &[u8; 512] = &pair_lookup(16, &Alphabet::LOWER);
+
+// The size of the pair lookup tables of every base from 2 up to, but excluding, the given base, which is also the
+// offset of that base's table in `UPPER_PAIR_LOOKUPS`.
+#[cfg(all(feature = "fast", not(feature = "small")))]
+const fn upper_pair_offset(base: usize) -> usize {
+    // Twice the sum of the squares from 2 to `base - 1`.
+    (base - 1) * base * (2 * base - 1) / 3 - 2
+}
+
+// Concatenates the pair lookup tables of `Alphabet::UPPER` in every base from 2 to 36.
+#[cfg(all(feature = "fast", not(feature = "small")))]
+const fn upper_pair_lookups() -> [u8; upper_pair_offset(37)] {
+    let mut table = [0u8; upper_pair_offset(37)];
+    let mut base = 2;
+    while base <= 36 {
+        let offset = upper_pair_offset(base);
+        let mut index = 0;
+        while index < base * base {
+            table[offset + index * 2] = Alphabet::UPPER.digit(index / base);
+            table[offset + index * 2 + 1] = Alphabet::UPPER.digit(index % base);
+            index += 1;
+        }
+        base += 1;
+    }
+    table
+}
+
+// The pair lookup tables of the `fast` feature for bases which are only known at runtime.
+#[cfg(all(feature = "fast", not(feature = "small")))]
+const UPPER_PAIR_LOOKUPS: &[u8; upper_pair_offset(37)] = &upper_pair_lookups();
 
+// The pair lookup table for a base which is only known at runtime. With the `fast` feature, every base whose digits
+// are shared with `Alphabet::UPPER` has one, and otherwise none do.
+#[cfg(all(feature = "fast", not(feature = "small")))]
+const fn runtime_pair_lookup(base: u32, alphabet: &Alphabet) -> &'static [u8] {
+    if base > alphabet.upper_digits() {
+        return &[];
+    }
+    let (_, table) = UPPER_PAIR_LOOKUPS.split_at(upper_pair_offset(base as usize));
+    table.split_at(2 * (base * base) as usize).0
+}
+
+#[cfg(not(all(feature = "fast", not(feature = "small"))))]
+const fn runtime_pair_lookup(_base: u32, _alphabet: &Alphabet) -> &'static [u8] {
+    &[]
+}
+
+// Writes the digits of `num` into the end of `string` for a base of `1 << shift`, using shifts and masks instead of
+// division. Numbers of every width are written by either the `u64` or the `u128` variant.
+//
+// # Safety
+// If `UNCHECKED` is set, `string` must be large enough to hold the digits.
+macro_rules! impl_power_of_two_write {
+    ($type_name:ty, $function_name:ident) => {
+        const unsafe fn $function_name<'a, const UNCHECKED: bool>(
+            mut num: $type_name,
+            shift: u32,
+            alphabet: &Alphabet,
+            string: &'a mut [MaybeUninit<u8>],
+        ) -> &'a [u8] {
+            #[cfg(not(feature = "small"))]
+            let lookup = match alphabet.hex_digits() {
+                HexDigits::Upper if shift == 4 => Some(HEX_UPPER_LOOKUP),
+                HexDigits::Lower if shift == 4 => Some(HEX_LOWER_LOOKUP),
+                _ => None,
+            };
+            #[cfg(feature = "small")]
+            let lookup: Option<&[u8; 512]> = None;
+
+            let mut index = string.len();
+            if let Some(lookup) = lookup {
+                // Decode two characters at the same time
+                while num > 0xFF {
+                    let pair = (num & 0xFF) as usize * 2;
+                    index -= 2;
+                    put!(string, index, lookup[pair]);
+                    put!(string, index + 1, lookup[pair + 1]);
+                    num >>= 8;
+                }
+                let pair = num as usize * 2;
+                if num > 0xF {
+                    index -= 2;
+                    put!(string, index, lookup[pair]);
+                    put!(string, index + 1, lookup[pair + 1]);
+                } else {
+                    index -= 1;
+                    put!(string, index, lookup[pair + 1]);
+                }
+            } else {
+                let mask = (1 << shift) - 1;
+                loop {
+                    index -= 1;
+                    put!(string, index, alphabet.digit((num & mask) as usize));
+                    num >>= shift;
+                    if num == 0 {
+                        break;
+                    }
+                }
+            }
+
+            written!(string, index)
+        }
+    };
+}
+
+impl_power_of_two_write!(u64, write_power_of_two_u64);
+#[cfg(feature = "u128")]
+impl_power_of_two_write!(u128, write_power_of_two_u128);
+
+#[cfg_attr(any(feature = "small", feature = "fast"), allow(unused_macros))]
 macro_rules! copy_2_dec_lut_bytes {
     ($to:ident,$to_index:expr,$lut_index:expr) => {
-        $to[$to_index as usize] = DEC_LOOKUP[$lut_index as usize];
-        $to[$to_index as usize + 1] = DEC_LOOKUP[$lut_index as usize + 1];
+        let (to, lut) = ($to_index as usize, $lut_index as usize);
+        put!($to, to, DEC_LOOKUP[lut]);
+        put!($to, to + 1, DEC_LOOKUP[lut + 1]);
     };
 }
 
-macro_rules! base_10 {
+#[cfg_attr(
+    not(all(feature = "fast", not(feature = "small"))),
+    allow(unused_macros)
+)]
+macro_rules! copy_4_dec_lut_bytes {
+    ($to:ident,$to_index:expr,$lut_index:expr) => {
+        let (to, lut) = ($to_index as usize, $lut_index as usize);
+        put!($to, to, DEC_QUAD_LOOKUP[lut]);
+        put!($to, to + 1, DEC_QUAD_LOOKUP[lut + 1]);
+        put!($to, to + 2, DEC_QUAD_LOOKUP[lut + 2]);
+        put!($to, to + 3, DEC_QUAD_LOOKUP[lut + 3]);
+    };
+}
+
+// The base 10 algorithm of the `small` feature, which writes one digit at a time without any lookup table.
+#[cfg_attr(not(feature = "small"), allow(unused_macros))]
+macro_rules! base_10_digits {
+    ($number:ident, $index:ident, $string:ident) => {
+        loop {
+            put!($string, $index, b'0' + ($number % 10) as u8);
+            $index = $index.wrapping_sub(1);
+            $number /= 10;
+            if $number == 0 {
+                break;
+            }
+        }
+    };
+}
+
+// The base 10 algorithm of the `fast` feature, which writes four digits at a time from `DEC_QUAD_LOOKUP`.
+#[cfg_attr(
+    not(all(feature = "fast", not(feature = "small"))),
+    allow(unused_macros)
+)]
+macro_rules! base_10_quads {
+    ($number:ident, $index:ident, $string:ident) => {
+        while $number > 9999 {
+            let rem = ($number % 10000) as usize * 4;
+            copy_4_dec_lut_bytes!($string, $index - 3, rem);
+            $index = $index.wrapping_sub(4);
+            $number /= 10000;
+        }
+        // The leading digits are the end of their four digit entry.
+        let quad = $number as usize * 4;
+        if $number > 999 {
+            copy_4_dec_lut_bytes!($string, $index - 3, quad);
+            $index = $index.wrapping_sub(4);
+        } else if $number > 99 {
+            put!($string, $index - 2, DEC_QUAD_LOOKUP[quad + 1]);
+            put!($string, $index - 1, DEC_QUAD_LOOKUP[quad + 2]);
+            put!($string, $index, DEC_QUAD_LOOKUP[quad + 3]);
+            $index = $index.wrapping_sub(3);
+        } else if $number > 9 {
+            put!($string, $index - 1, DEC_QUAD_LOOKUP[quad + 2]);
+            put!($string, $index, DEC_QUAD_LOOKUP[quad + 3]);
+            $index = $index.wrapping_sub(2);
+        } else {
+            put!($string, $index, DEC_QUAD_LOOKUP[quad + 3]);
+            $index = $index.wrapping_sub(1);
+        }
+    };
+}
+
+// The base 10 algorithm of the default tables, which writes two digits at a time from `DEC_LOOKUP`.
+#[cfg_attr(any(feature = "small", feature = "fast"), allow(unused_macros))]
+macro_rules! base_10_pairs {
     ($number:ident, $index:ident, $string:ident) => {
         // Decode four characters at the same time
         while $number > 9999 {
@@ -55,275 +326,1421 @@ macro_rules! base_10 {
         } else if $number > 99 {
             let section = ($number as u16 / 10) * 2;
             copy_2_dec_lut_bytes!($string, $index - 2, section);
-            $string[$index] = LOOKUP[($number % 10) as usize];
+            put!($string, $index, LOOKUP[($number % 10) as usize]);
             $index = $index.wrapping_sub(3);
         } else if $number > 9 {
             $number *= 2;
             copy_2_dec_lut_bytes!($string, $index - 1, $number);
             $index = $index.wrapping_sub(2);
         } else {
-            $string[$index] = LOOKUP[$number as usize];
+            put!($string, $index, LOOKUP[$number as usize]);
             $index = $index.wrapping_sub(1);
         }
     };
 }
 
-macro_rules! impl_unsigned_numtoa_for {
-    (
-        $type_name:ty,
-        $core_function_name:ident,
-        $str_function_name:ident
-    ) => {
-        pub const fn $core_function_name(
-            mut num: $type_name,
-            base: $type_name,
-            string: &mut [u8],
-        ) -> &[u8] {
-            // Check if the buffer is large enough and panic on debug builds if it isn't
-            if cfg!(debug_assertions) {
-                debug_assert!(
-                    base > 1 && base as u128 <= MAX_SUPPORTED_BASE,
-                    "unsupported base"
-                );
-                debug_assert!(
-                    string.len() >= required_space(base as u128, <$type_name>::MAX as u128, false)
-                );
-            }
+// The base 10 algorithm for integers of up to 16 bits, and for the leading digits of wider integers.
+#[cfg(feature = "small")]
+macro_rules! base_10 {
+    ($($args:tt)*) => {
+        base_10_digits!($($args)*)
+    };
+}
 
-            let mut index = string.len() - 1;
-            if num == 0 {
-                string[index] = b'0';
-                return string.split_at(index).1;
-            }
+#[cfg(all(feature = "fast", not(feature = "small")))]
+macro_rules! base_10 {
+    ($($args:tt)*) => {
+        base_10_quads!($($args)*)
+    };
+}
 
-            if base == 10 {
-                // Convert using optimized base 10 algorithm
-                base_10!(num, index, string);
-            } else {
-                while num != 0 {
-                    let rem = num % base;
-                    string[index] = LOOKUP[rem as usize];
-                    index = index.wrapping_sub(1);
-                    num /= base;
-                }
-            }
+#[cfg(not(any(feature = "small", feature = "fast")))]
+macro_rules! base_10 {
+    ($($args:tt)*) => {
+        base_10_pairs!($($args)*)
+    };
+}
+
+// Converts a number below 10^8 into eight ASCII digits within a `u64`, with the most significant digit in the lowest
+// byte. Each step splits every lane of the register in half with a multiply and shift which divides exactly in
+// that range: into 4 digit halves, then 2 digit quarters, and finally single digits.
+pub(crate) const fn swar_8_digits(num: u32) -> u64 {
+    let halves = (num / 10000) as u64 | ((num % 10000) as u64) << 32;
+    let high = ((halves * 10486) >> 20) & 0x0000_007F_0000_007F;
+    let quarters = high | (halves - 100 * high) << 16;
+    let tens = ((quarters * 103) >> 10) & 0x000F_000F_000F_000F;
+    let digits = tens | (quarters - 10 * tens) << 8;
+    digits + 0x3030_3030_3030_3030
+}
 
-            string.split_at(index.wrapping_add(1)).1
+// Stores the eight digits from `swar_8_digits` into `string`, starting at `index`.
+//
+// # Safety
+// If `UNCHECKED` is set, `string` must hold at least eight bytes from `index`.
+#[cfg(all(
+    feature = "swar",
+    not(any(
+        feature = "small",
+        feature = "fast",
+        feature = "reciprocal",
+        not(target_pointer_width = "64")
+    ))
+))]
+const unsafe fn store_8_digits<const UNCHECKED: bool>(
+    digits: u64,
+    string: &mut [MaybeUninit<u8>],
+    index: usize,
+) {
+    if UNCHECKED {
+        (string.as_mut_ptr().add(index) as *mut [u8; 8]).write_unaligned(digits.to_le_bytes());
+        return;
+    }
+    match string.split_at_mut(index).1.first_chunk_mut::<8>() {
+        Some(chunk) => {
+            *chunk = unsafe {
+                core::mem::transmute::<[u8; 8], [MaybeUninit<u8>; 8]>(digits.to_le_bytes())
+            }
         }
+        None => panic!("index out of bounds"),
+    }
+}
 
-        pub const fn $str_function_name(
-            num: $type_name,
-            base: $type_name,
-            string: &mut [u8],
-        ) -> &str {
-            unsafe { core::str::from_utf8_unchecked($core_function_name(num, base, string)) }
+// The base 10 algorithm for integers of up to 64 bits, which converts eight digits at a time within a `u64` and
+// stores them at once, leaving the remaining leading digits to `base_10!`.
+#[cfg(all(
+    feature = "swar",
+    not(any(
+        feature = "small",
+        feature = "fast",
+        feature = "reciprocal",
+        not(target_pointer_width = "64")
+    ))
+))]
+macro_rules! base_10_u64 {
+    ($number:ident, $index:ident, $string:ident) => {
+        let mut number = $number as u64;
+        while number >= 100_000_000 {
+            let chunk = (number % 100_000_000) as u32;
+            number /= 100_000_000;
+            $index = $index.wrapping_sub(8);
+            unsafe {
+                store_8_digits::<UNCHECKED>(swar_8_digits(chunk), $string, $index.wrapping_add(1))
+            };
         }
+        let mut number = number as u32;
+        base_10!(number, $index, $string);
     };
 }
 
-macro_rules! impl_signed_numtoa_for {
-    (
-        $type_name:ty,
-        $core_function_name:ident,
-        $str_function_name:ident
-    ) => {
-        pub const fn $core_function_name(
-            mut num: $type_name,
-            base: $type_name,
-            string: &mut [u8],
-        ) -> &[u8] {
-            if cfg!(debug_assertions) {
-                debug_assert!(
-                    base > 1 && base as u128 <= MAX_SUPPORTED_BASE,
-                    "unsupported base"
-                );
-                debug_assert!(
-                    string.len()
-                        >= required_space(
-                            base as u128,
-                            <$type_name>::MIN.unsigned_abs() as u128,
-                            true
-                        )
-                );
-            }
-
-            let mut index = string.len() - 1;
-            let mut is_negative = false;
+// The base 10 algorithm for integers of up to 64 bits on 64 bit targets without the `swar` feature, or with the `fast`
+// feature, which writes the whole number with `base_10!`.
+#[cfg(all(
+    any(not(feature = "swar"), feature = "fast"),
+    not(any(
+        feature = "small",
+        feature = "reciprocal",
+        not(target_pointer_width = "64")
+    ))
+))]
+macro_rules! base_10_u64 {
+    ($number:ident, $index:ident, $string:ident) => {
+        let mut number = $number as u64;
+        base_10!(number, $index, $string);
+    };
+}
 
-            if num < 0 {
-                is_negative = true;
-                num = match num.checked_abs() {
-                    Some(value) => value,
-                    None => {
-                        let value = <$type_name>::max_value();
-                        string[index] = LOOKUP[((value % base + 1) % base) as usize];
-                        index -= 1;
-                        value / base + ((value % base == base - 1) as $type_name)
-                    }
-                };
-            } else if num == 0 {
-                string[index] = b'0';
-                return string.split_at(index).1;
-            }
+// The high 32 bits of the product of `x` and `y`, computed from 16 bit halves so that targets without a 64 bit
+// multiplication do not need to call into a software routine.
+#[cfg_attr(
+    not(any(feature = "reciprocal", not(target_pointer_width = "64"))),
+    allow(dead_code)
+)]
+const fn mul_high_u32(x: u32, y: u32) -> u32 {
+    let (x_low, x_high) = (x & 0xFFFF, x >> 16);
+    let (y_low, y_high) = (y & 0xFFFF, y >> 16);
+    let (high_low, low_high) = (x_high * y_low, x_low * y_high);
+    let middle = ((x_low * y_low) >> 16) + (high_low & 0xFFFF) + (low_high & 0xFFFF);
+    x_high * y_high + (high_low >> 16) + (low_high >> 16) + (middle >> 16)
+}
 
-            if base == 10 {
-                // Convert using optimized base 10 algorithm
-                base_10!(num, index, string);
-            } else {
-                while num != 0 {
-                    let rem = num % base;
-                    string[index] = LOOKUP[rem as usize];
-                    index = index.wrapping_sub(1);
-                    num /= base;
-                }
-            }
+// Divides by 10^4, multiplying by ceil(2^45 / 10^4) which is exact for every `u32`.
+#[cfg_attr(
+    not(any(feature = "reciprocal", not(target_pointer_width = "64"))),
+    allow(dead_code)
+)]
+const fn div_rem_10000_u32(num: u32) -> (u32, u32) {
+    let quotient = mul_high_u32(num, 0xD1B7_1759) >> 13;
+    (quotient, num - quotient * 10000)
+}
 
-            if is_negative {
-                string[index] = b'-';
-                index = index.wrapping_sub(1);
-            }
+// Divides by 100, multiplying by ceil(2^37 / 100) which is exact for every `u32`.
+#[cfg_attr(
+    any(
+        feature = "small",
+        not(any(feature = "reciprocal", not(target_pointer_width = "64")))
+    ),
+    allow(dead_code)
+)]
+const fn div_rem_100_u32(num: u32) -> (u32, u32) {
+    let quotient = mul_high_u32(num, 0x51EB_851F) >> 5;
+    (quotient, num - quotient * 100)
+}
 
-            string.split_at(index.wrapping_add(1)).1
-        }
+// Divides a `u64` by 10^4 as a long division of its 32 bit high half followed by two 16 bit digits, each of which
+// leaves a dividend small enough for `div_rem_10000_u32`.
+#[cfg_attr(
+    not(any(feature = "reciprocal", not(target_pointer_width = "64"))),
+    allow(dead_code)
+)]
+const fn div_rem_10000_u64(num: u64) -> (u64, u32) {
+    let (high, remainder) = div_rem_10000_u32((num >> 32) as u32);
+    let (middle, remainder) = div_rem_10000_u32(remainder << 16 | (num as u32) >> 16);
+    let (low, remainder) = div_rem_10000_u32(remainder << 16 | (num as u32) & 0xFFFF);
+    ((high as u64) << 32 | (middle << 16 | low) as u64, remainder)
+}
 
-        pub const fn $str_function_name(
-            num: $type_name,
-            base: $type_name,
-            string: &mut [u8],
-        ) -> &str {
-            unsafe { core::str::from_utf8_unchecked($core_function_name(num, base, string)) }
-        }
+// Writes four digits ending at `index`, from a number below 10^4.
+#[cfg(all(
+    not(any(feature = "small", feature = "fast")),
+    any(feature = "reciprocal", not(target_pointer_width = "64"))
+))]
+macro_rules! write_4_digits_reciprocal {
+    ($number:expr, $index:ident, $string:ident) => {
+        let (frst, scnd) = div_rem_100_u32($number);
+        copy_2_dec_lut_bytes!($string, $index - 3, frst * 2);
+        copy_2_dec_lut_bytes!($string, $index - 1, scnd * 2);
+        $index = $index.wrapping_sub(4);
     };
 }
 
-impl_signed_numtoa_for!(i16, numtoa_i16, numtoa_i16_str);
-impl_signed_numtoa_for!(i32, numtoa_i32, numtoa_i32_str);
-impl_signed_numtoa_for!(i64, numtoa_i64, numtoa_i64_str);
-impl_signed_numtoa_for!(i128, numtoa_i128, numtoa_i128_str);
-impl_signed_numtoa_for!(isize, numtoa_isize, numtoa_isize_str);
-impl_unsigned_numtoa_for!(u16, numtoa_u16, numtoa_u16_str);
-impl_unsigned_numtoa_for!(u32, numtoa_u32, numtoa_u32_str);
-impl_unsigned_numtoa_for!(u64, numtoa_u64, numtoa_u64_str);
-impl_unsigned_numtoa_for!(u128, numtoa_u128, numtoa_u128_str);
-impl_unsigned_numtoa_for!(usize, numtoa_usize, numtoa_usize_str);
-
-pub const fn numtoa_i8(mut num: i8, base: i8, string: &mut [u8]) -> &[u8] {
-    if cfg!(debug_assertions) {
-        debug_assert!(
-            base > 1 && base as u128 <= MAX_SUPPORTED_BASE,
-            "unsupported base"
-        );
-        debug_assert!(
-            string.len() >= required_space(base as u128, i8::MIN.unsigned_abs() as u128, true)
-        );
-    }
+#[cfg(all(
+    feature = "fast",
+    not(feature = "small"),
+    any(feature = "reciprocal", not(target_pointer_width = "64"))
+))]
+macro_rules! write_4_digits_reciprocal {
+    ($number:expr, $index:ident, $string:ident) => {
+        copy_4_dec_lut_bytes!($string, $index - 3, $number as usize * 4);
+        $index = $index.wrapping_sub(4);
+    };
+}
 
-    let mut index = string.len() - 1;
-    let mut is_negative = false;
+// The base 10 algorithm for integers of up to 64 bits on targets where 64 bit division, or even 64 bit
+// multiplication, is done in software. Every division by a constant is replaced by `u32` multiplications.
+#[cfg(all(
+    not(feature = "small"),
+    any(feature = "reciprocal", not(target_pointer_width = "64"))
+))]
+macro_rules! base_10_u64 {
+    ($number:ident, $index:ident, $string:ident) => {
+        let mut number = $number as u64;
+        while number > u32::MAX as u64 {
+            let (quotient, remainder) = div_rem_10000_u64(number);
+            write_4_digits_reciprocal!(remainder, $index, $string);
+            number = quotient;
+        }
+        let mut number = number as u32;
+        while number > 9999 {
+            let (quotient, remainder) = div_rem_10000_u32(number);
+            write_4_digits_reciprocal!(remainder, $index, $string);
+            number = quotient;
+        }
+        if number > 999 {
+            write_4_digits_reciprocal!(number, $index, $string);
+        } else {
+            if number > 99 {
+                let (quotient, remainder) = div_rem_100_u32(number);
+                copy_2_dec_lut_bytes!($string, $index - 1, remainder * 2);
+                $index = $index.wrapping_sub(2);
+                number = quotient;
+            }
+            if number > 9 {
+                copy_2_dec_lut_bytes!($string, $index - 1, number * 2);
+                $index = $index.wrapping_sub(2);
+            } else {
+                put!($string, $index, LOOKUP[number as usize]);
+                $index = $index.wrapping_sub(1);
+            }
+        }
+    };
+}
 
-    if num < 0 {
-        is_negative = true;
-        num = match num.checked_abs() {
-            Some(value) => value,
-            None => {
-                let value = <i8>::max_value();
-                string[index] = LOOKUP[((value % base + 1) % base) as usize];
-                index -= 1;
-                value / base + ((value % base == base - 1) as i8)
+// The base 10 algorithm for integers of up to 64 bits with the `small` feature. Where 64 bit division is done in
+// software, four digits at a time are split off with `u32` multiplications until the rest fits in a `u32`.
+#[cfg(feature = "small")]
+macro_rules! base_10_u64 {
+    ($number:ident, $index:ident, $string:ident) => {
+        let mut number = $number as u64;
+        #[cfg(any(feature = "reciprocal", not(target_pointer_width = "64")))]
+        let mut number = {
+            while number > u32::MAX as u64 {
+                let (quotient, mut remainder) = div_rem_10000_u64(number);
+                let end = $index.wrapping_sub(4);
+                while $index != end {
+                    put!($string, $index, b'0' + (remainder % 10) as u8);
+                    $index = $index.wrapping_sub(1);
+                    remainder /= 10;
+                }
+                number = quotient;
             }
+            number as u32
         };
-    } else if num == 0 {
-        string[index] = b'0';
-        return string.split_at(index).1;
-    }
-
-    if base == 10 {
-        if num > 99 {
-            let section = (num / 10) * 2;
-            copy_2_dec_lut_bytes!(string, index - 2, section);
-            string[index] = LOOKUP[(num % 10) as usize];
-            index = index.wrapping_sub(3);
-        } else if num > 9 {
-            let idx = num as usize * 2;
-            copy_2_dec_lut_bytes!(string, index - 1, idx);
-            index = index.wrapping_sub(2);
+        base_10_digits!(number, $index, $string);
+    };
+}
+
+// The base 10 algorithm for `u8`, which can hold at most three digits.
+#[cfg(not(any(feature = "small", feature = "fast")))]
+macro_rules! base_10_u8 {
+    ($number:ident, $index:ident, $string:ident) => {
+        if $number > 99 {
+            let section = ($number / 10) * 2;
+            copy_2_dec_lut_bytes!($string, $index - 2, section);
+            put!($string, $index, LOOKUP[($number % 10) as usize]);
+            $index = $index.wrapping_sub(3);
+        } else if $number > 9 {
+            $number *= 2;
+            copy_2_dec_lut_bytes!($string, $index - 1, $number);
+            $index = $index.wrapping_sub(2);
         } else {
-            string[index] = LOOKUP[num as usize];
-            index = index.wrapping_sub(1);
-        }
-    } else {
-        while num != 0 {
-            let rem = num % base;
-            string[index] = LOOKUP[rem as usize];
-            index = index.wrapping_sub(1);
-            num /= base;
+            put!($string, $index, LOOKUP[$number as usize]);
+            $index = $index.wrapping_sub(1);
         }
-    }
+    };
+}
 
-    if is_negative {
-        string[index] = b'-';
-        index = index.wrapping_sub(1);
-    }
+#[cfg(feature = "small")]
+macro_rules! base_10_u8 {
+    ($($args:tt)*) => {
+        base_10_digits!($($args)*)
+    };
+}
 
-    string.split_at(index.wrapping_add(1)).1
+#[cfg(all(feature = "fast", not(feature = "small")))]
+macro_rules! base_10_u8 {
+    ($number:ident, $index:ident, $string:ident) => {
+        let mut number = $number as u16;
+        base_10_quads!(number, $index, $string);
+    };
 }
 
-pub const fn numtoa_i8_str(num: i8, base: i8, string: &mut [u8]) -> &str {
-    unsafe { str::from_utf8_unchecked(numtoa_i8(num, base, string)) }
+// The largest power of ten which fits in a `u64`.
+#[cfg(feature = "u128")]
+const POW10_19: u64 = 10_000_000_000_000_000_000;
+
+// The high 128 bits of the 256-bit product of `x` and `y`, computed from 64-bit halves.
+#[cfg(feature = "u128")]
+const fn mul_high_u128(x: u128, y: u128) -> u128 {
+    let (x_low, x_high) = (x as u64 as u128, x >> 64);
+    let (y_low, y_high) = (y as u64 as u128, y >> 64);
+    let middle = x_low * y_high + ((x_low * y_low) >> 64);
+    let carry = (x_high * y_low + (middle as u64 as u128)) >> 64;
+    x_high * y_high + (middle >> 64) + carry
 }
 
-pub const fn numtoa_u8(mut num: u8, base: u8, string: &mut [u8]) -> &[u8] {
-    if cfg!(debug_assertions) {
-        debug_assert!(
-            base > 1 && base as u128 <= MAX_SUPPORTED_BASE,
-            "unsupported base"
-        );
-        debug_assert!(string.len() >= required_space(base as u128, u8::MAX as u128, false));
-    }
+// Divides by 10^19, returning the quotient and remainder without calling the 128-bit division routine.
+#[cfg(feature = "u128")]
+const fn div_rem_pow10_19(num: u128) -> (u128, u64) {
+    let quotient = if num < 1 << 83 {
+        // 10^19 is 5^19 * 2^19, so the low bits can be shifted out first to fit the division in 64 bits.
+        ((num >> 19) as u64 / (POW10_19 >> 19)) as u128
+    } else {
+        // ceil(2^190 / 10^19), whose error times 2^128 stays below 2^190, so the quotient is exact for every `u128`.
+        mul_high_u128(num, 156_927_543_384_667_019_095_894_735_580_191_660_403) >> 62
+    };
+    (quotient, (num - quotient * POW10_19 as u128) as u64)
+}
 
-    let mut index = string.len() - 1;
-    if num == 0 {
-        string[index] = b'0';
-        return string.split_at(index).1;
-    }
+// The base 10 algorithm for `u128`, which writes 19 digits at a time with the `u64` algorithm.
+#[cfg(feature = "u128")]
+macro_rules! base_10_u128 {
+    ($number:ident, $index:ident, $string:ident) => {
+        while $number > u64::MAX as u128 {
+            let (quotient, remainder) = div_rem_pow10_19($number);
+            let end = $index.wrapping_sub(19);
+            base_10_u64!(remainder, $index, $string);
+            while $index != end {
+                put!($string, $index, b'0');
+                $index = $index.wrapping_sub(1);
+            }
+            $number = quotient;
+        }
+        base_10_u64!($number, $index, $string);
+    };
+}
 
-    if base == 10 {
-        if num > 99 {
-            let section = (num / 10) * 2;
-            copy_2_dec_lut_bytes!(string, index - 2, section);
-            string[index] = LOOKUP[(num % 10) as usize];
-            index = index.wrapping_sub(3);
-        } else if num > 9 {
-            num *= 2;
-            copy_2_dec_lut_bytes!(string, index - 1, num);
-            index = index.wrapping_sub(2);
+// The algorithm for every other base, which writes two digits at a time from `pairs` unless it is empty.
+macro_rules! base_n {
+    ($number:ident, $base:ident, $alphabet:ident, $pairs:ident, $index:ident, $string:ident) => {
+        if !$pairs.is_empty() {
+            // Decode two characters at the same time, unless the square of the base overflows, in which case
+            // any number has at most two digits.
+            if let Some(square) = $base.checked_mul($base) {
+                while $number >= square {
+                    let pair = ($number % square) as usize * 2;
+                    put!($string, $index - 1, $pairs[pair]);
+                    put!($string, $index, $pairs[pair + 1]);
+                    $index = $index.wrapping_sub(2);
+                    $number /= square;
+                }
+            }
+            let pair = $number as usize * 2;
+            if $number >= $base {
+                put!($string, $index - 1, $pairs[pair]);
+                put!($string, $index, $pairs[pair + 1]);
+                $index = $index.wrapping_sub(2);
+            } else {
+                put!($string, $index, $pairs[pair + 1]);
+                $index = $index.wrapping_sub(1);
+            }
         } else {
-            string[index] = LOOKUP[num as usize];
-            index = index.wrapping_sub(1);
-        }
-    } else {
-        while num != 0 {
-            let rem = num % base;
-            string[index] = LOOKUP[rem as usize];
-            index = index.wrapping_sub(1);
-            num /= base;
+            while $number != 0 {
+                let rem = $number % $base;
+                put!($string, $index, $alphabet.digit(rem as usize));
+                $index = $index.wrapping_sub(1);
+                $number /= $base;
+            }
         }
+    };
+}
+
+// The largest power of the base which fits in a `u64`, and its exponent.
+#[cfg(feature = "u128")]
+const fn chunk_power(base: u64) -> (u64, usize) {
+    let (mut power, mut digits) = (base, 1);
+    while let Some(next) = power.checked_mul(base) {
+        power = next;
+        digits += 1;
     }
+    (power, digits)
+}
 
-    string.split_at(index.wrapping_add(1)).1
+// The algorithm for every other base for `u128`, which divides by the largest power of the base that fits in a `u64`
+// and writes each chunk with `u64` arithmetic, so that a single 128-bit division replaces up to 40 of them.
+#[cfg(feature = "u128")]
+macro_rules! base_n_u128 {
+    ($number:ident, $base:ident, $alphabet:ident, $pairs:ident, $index:ident, $string:ident) => {
+        let base = $base as u64;
+        let (power, digits) = chunk_power(base);
+        while $number > u64::MAX as u128 {
+            let quotient = $number / power as u128;
+            let mut chunk = ($number - quotient * power as u128) as u64;
+            let end = $index.wrapping_sub(digits);
+            base_n!(chunk, base, $alphabet, $pairs, $index, $string);
+            while $index != end {
+                put!($string, $index, $alphabet.digit(0));
+                $index = $index.wrapping_sub(1);
+            }
+            $number = quotient;
+        }
+        let mut number = $number as u64;
+        base_n!(number, base, $alphabet, $pairs, $index, $string);
+    };
 }
 
-pub const fn numtoa_u8_str(num: u8, base: u8, string: &mut [u8]) -> &str {
-    unsafe { str::from_utf8_unchecked(numtoa_u8(num, base, string)) }
+// Builds a table of the powers of ten that fit in the given type, starting from one.
+macro_rules! powers_of_ten {
+    ($type_name:ty, $length:expr) => {{
+        let mut table: [$type_name; $length] = [1; $length];
+        let mut index = 1;
+        while index < $length {
+            table[index] = table[index - 1] * 10;
+            index += 1;
+        }
+        table
+    }};
 }
 
-#[cfg(test)]
-mod core_test {
+const POW10_U64: [u64; 20] = powers_of_ten!(u64, 20);
+const POW10_U128: [u128; 39] = powers_of_ten!(u128, 39);
 
-    use super::*;
+// Counts the digits of a number for the given base, estimating the base 10 logarithm from the bit length and
+// correcting it with a table of powers of ten, and dividing the bit length by the bits per digit in power of two
+// bases.
+macro_rules! impl_count_digits {
+    ($type_name:ty, $function_name:ident, $powers_of_ten:ident) => {
+        pub(crate) const fn $function_name(mut num: $type_name, base: u32) -> usize {
+            // Zero has as many digits as one.
+            let nonzero = num | 1;
+            let bits = <$type_name>::BITS - nonzero.leading_zeros();
+            if base == 10 {
+                // This is floor(log10(2^bits)) for every bit length up to 128, so there are either that many digits
+                // or one more.
+                let estimate = (bits * 1233 >> 12) as usize;
+                estimate + 1 - (nonzero < $powers_of_ten[estimate]) as usize
+            } else if base.is_power_of_two() {
+                bits.div_ceil(base.trailing_zeros()) as usize
+            } else {
+                let base = base as $type_name;
+                let mut digits = 1;
+                while num >= base {
+                    num /= base;
+                    digits += 1;
+                }
+                digits
+            }
+        }
+    };
+}
 
-    fn verify_required_sizes_for_base(
-        base: u128,
+impl_count_digits!(u64, count_digits_u64, POW10_U64);
+impl_count_digits!(u128, count_digits_u128, POW10_U128);
+
+// Validates a base given as any integer type, evaluating to a `Result<Radix, NumToAError>`.
+macro_rules! radix_from_base {
+    ($base:expr, $type_name:ty) => {
+        if $base >= Radix::MIN.get() as $type_name && $base <= Radix::MAX.get() as $type_name {
+            Ok(Radix::new_unchecked($base as u8))
+        } else {
+            Err(NumToAError::UnsupportedBase)
+        }
+    };
+}
+
+macro_rules! impl_unsigned_numtoa_for {
+    (
+        $type_name:ty,
+        $base_10:ident,
+        $base_n:ident,
+        $power_of_two_function_name:ident,
+        $count_digits_function_name:ident,
+        $core_function_name:ident,
+        $str_function_name:ident,
+        $radix_function_name:ident,
+        $radix_str_function_name:ident,
+        $alphabet_function_name:ident,
+        $alphabet_str_function_name:ident,
+        $in_alphabet_function_name:ident,
+        $in_alphabet_str_function_name:ident,
+        $try_function_name:ident,
+        $try_str_function_name:ident,
+        $left_function_name:ident,
+        $uninit_function_name:ident,
+        $uninit_str_function_name:ident,
+        $unchecked_function_name:ident,
+        $unchecked_str_function_name:ident,
+        $digit_count_function_name:ident,
+        $write_function_name:ident,
+        $write_pairs_function_name:ident,
+        $count_function_name:ident
+    ) => {
+        // Writes the digits of `num` into the end of `string` without validating the buffer length. The base must
+        // be within 2 and the number of digits in the alphabet.
+        pub(crate) const fn $write_function_name<'a>(
+            num: $type_name,
+            base: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [MaybeUninit<u8>],
+        ) -> &'a [u8] {
+            // Powers of two are dispatched first, as they never need a pair lookup table.
+            if base.is_power_of_two() {
+                return unsafe {
+                    $power_of_two_function_name::<false>(
+                        num as _,
+                        base.trailing_zeros(),
+                        alphabet,
+                        string,
+                    )
+                };
+            }
+
+            let pairs = runtime_pair_lookup(base as u32, alphabet);
+            unsafe { $write_pairs_function_name::<false>(num, base, alphabet, pairs, string) }
+        }
+
+        // Writes the digits of `num` like the above, two at a time from `pairs` unless it is empty. The table must
+        // have been built by `pair_lookup` with the same base and alphabet.
+        //
+        // # Safety
+        // If `UNCHECKED` is set, `string` must be large enough to hold the digits.
+        #[inline(always)]
+        pub(crate) const unsafe fn $write_pairs_function_name<'a, const UNCHECKED: bool>(
+            mut num: $type_name,
+            base: $type_name,
+            alphabet: &Alphabet,
+            pairs: &[u8],
+            string: &'a mut [MaybeUninit<u8>],
+        ) -> &'a [u8] {
+            if base.is_power_of_two() {
+                return $power_of_two_function_name::<UNCHECKED>(
+                    num as _,
+                    base.trailing_zeros(),
+                    alphabet,
+                    string,
+                );
+            }
+
+            let mut index = string.len() - 1;
+            if num == 0 {
+                put!(string, index, alphabet.digit(0));
+                return written!(string, index);
+            }
+
+            if base == 10 && alphabet.is_decimal() {
+                // Convert using optimized base 10 algorithm
+                $base_10!(num, index, string);
+            } else {
+                $base_n!(num, base, alphabet, pairs, index, string);
+            }
+
+            written!(string, index.wrapping_add(1))
+        }
+
+        // Counts the number of digits in `num` for the given base.
+        pub(crate) const fn $count_function_name(num: $type_name, base: $type_name) -> usize {
+            $count_digits_function_name(num as _, base as u32)
+        }
+
+        pub const fn $core_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &[u8] {
+            match radix_from_base!(base, $type_name) {
+                Ok(radix) => $radix_function_name(num, radix, string),
+                Err(_) => panic!("unsupported base"),
+            }
+        }
+
+        pub const fn $str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($core_function_name(num, base, string)) }
+        }
+
+        pub const fn $radix_function_name(
+            num: $type_name,
+            radix: Radix,
+            string: &mut [u8],
+        ) -> &[u8] {
+            $alphabet_function_name(num, radix, &Alphabet::UPPER, string)
+        }
+
+        pub const fn $radix_str_function_name(
+            num: $type_name,
+            radix: Radix,
+            string: &mut [u8],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($radix_function_name(num, radix, string)) }
+        }
+
+        pub const fn $alphabet_function_name<'a>(
+            num: $type_name,
+            radix: Radix,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a [u8] {
+            assert!(radix.get() <= alphabet.max_base(), "unsupported base");
+
+            // Check if the buffer is large enough and panic on debug builds if it isn't
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len()
+                        >= $count_function_name(<$type_name>::MAX, radix.get() as $type_name)
+                );
+            }
+
+            $write_function_name(
+                num,
+                radix.get() as $type_name,
+                alphabet,
+                as_uninit_mut(string),
+            )
+        }
+
+        pub const fn $alphabet_str_function_name<'a>(
+            num: $type_name,
+            radix: Radix,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a str {
+            unsafe {
+                core::str::from_utf8_unchecked($alphabet_function_name(
+                    num, radix, alphabet, string,
+                ))
+            }
+        }
+
+        pub const fn $in_alphabet_function_name<'a>(
+            num: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a [u8] {
+            let base = alphabet.max_base();
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len() >= $count_function_name(<$type_name>::MAX, base as $type_name)
+                );
+            }
+
+            $write_function_name(num, base as $type_name, alphabet, as_uninit_mut(string))
+        }
+
+        pub const fn $in_alphabet_str_function_name<'a>(
+            num: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a str {
+            unsafe {
+                core::str::from_utf8_unchecked($in_alphabet_function_name(num, alphabet, string))
+            }
+        }
+
+        pub const fn $try_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> Result<&[u8], NumToAError> {
+            let radix = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix,
+                Err(why) => return Err(why),
+            };
+
+            let needed = $count_function_name(num, radix.get() as $type_name);
+            if string.len() < needed {
+                return Err(NumToAError::BufferTooSmall { needed });
+            }
+
+            Ok($write_function_name(
+                num,
+                radix.get() as $type_name,
+                &Alphabet::UPPER,
+                as_uninit_mut(string),
+            ))
+        }
+
+        pub const fn $try_str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> Result<&str, NumToAError> {
+            match $try_function_name(num, base, string) {
+                Ok(slice) => Ok(unsafe { core::str::from_utf8_unchecked(slice) }),
+                Err(why) => Err(why),
+            }
+        }
+
+        pub const fn $left_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> usize {
+            let base = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix.get() as $type_name,
+                Err(_) => panic!("unsupported base"),
+            };
+
+            let length = $count_function_name(num, base);
+            $write_function_name(
+                num,
+                base,
+                &Alphabet::UPPER,
+                as_uninit_mut(string.split_at_mut(length).0),
+            );
+            length
+        }
+
+        pub const fn $uninit_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [MaybeUninit<u8>],
+        ) -> &[u8] {
+            let base = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix.get() as $type_name,
+                Err(_) => panic!("unsupported base"),
+            };
+
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len() >= $count_function_name(<$type_name>::MAX, base as $type_name)
+                );
+            }
+
+            $write_function_name(num, base, &Alphabet::UPPER, string)
+        }
+
+        pub const fn $uninit_str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [MaybeUninit<u8>],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($uninit_function_name(num, base, string)) }
+        }
+
+        /// # Safety
+        /// `string` must be large enough to hold any value of this type in the given base, as given by the
+        /// `REQUIRED_SPACE` constants of [crate::BaseN]. On debug builds, this is checked.
+        pub const unsafe fn $unchecked_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &[u8] {
+            let base = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix.get() as $type_name,
+                Err(_) => panic!("unsupported base"),
+            };
+
+            if cfg!(debug_assertions) {
+                debug_assert!(string.len() >= $count_function_name(<$type_name>::MAX, base));
+            }
+
+            if base.is_power_of_two() {
+                return $power_of_two_function_name::<true>(
+                    num as _,
+                    base.trailing_zeros(),
+                    &Alphabet::UPPER,
+                    as_uninit_mut(string),
+                );
+            }
+
+            let pairs = runtime_pair_lookup(base as u32, &Alphabet::UPPER);
+            $write_pairs_function_name::<true>(
+                num,
+                base,
+                &Alphabet::UPPER,
+                pairs,
+                as_uninit_mut(string),
+            )
+        }
+
+        /// # Safety
+        /// As with the above.
+        pub const unsafe fn $unchecked_str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &str {
+            core::str::from_utf8_unchecked($unchecked_function_name(num, base, string))
+        }
+
+        pub const fn $digit_count_function_name(num: $type_name, radix: Radix) -> usize {
+            $count_function_name(num, radix.get() as $type_name)
+        }
+    };
+}
+
+macro_rules! impl_signed_numtoa_for {
+    (
+        $type_name:ty,
+        $unsigned_type_name:ty,
+        $core_function_name:ident,
+        $str_function_name:ident,
+        $radix_function_name:ident,
+        $radix_str_function_name:ident,
+        $alphabet_function_name:ident,
+        $alphabet_str_function_name:ident,
+        $in_alphabet_function_name:ident,
+        $in_alphabet_str_function_name:ident,
+        $try_function_name:ident,
+        $try_str_function_name:ident,
+        $left_function_name:ident,
+        $uninit_function_name:ident,
+        $uninit_str_function_name:ident,
+        $unchecked_function_name:ident,
+        $unchecked_str_function_name:ident,
+        $digit_count_function_name:ident,
+        $write_function_name:ident,
+        $write_pairs_function_name:ident,
+        $unsigned_write_pairs_function_name:ident,
+        $unsigned_count_function_name:ident
+    ) => {
+        // Writes the magnitude of `num`, then prepends the sign if it is negative.
+        pub(crate) const fn $write_function_name<'a>(
+            num: $type_name,
+            base: $unsigned_type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [MaybeUninit<u8>],
+        ) -> &'a [u8] {
+            let pairs = runtime_pair_lookup(base as u32, alphabet);
+            unsafe { $write_pairs_function_name::<false>(num, base, alphabet, pairs, string) }
+        }
+
+        // Writes the magnitude of `num` two digits at a time from `pairs`, then prepends the sign if it is negative.
+        //
+        // # Safety
+        // If `UNCHECKED` is set, `string` must be large enough to hold the digits and the sign.
+        pub(crate) const unsafe fn $write_pairs_function_name<'a, const UNCHECKED: bool>(
+            num: $type_name,
+            base: $unsigned_type_name,
+            alphabet: &Alphabet,
+            pairs: &[u8],
+            string: &'a mut [MaybeUninit<u8>],
+        ) -> &'a [u8] {
+            let written = $unsigned_write_pairs_function_name::<UNCHECKED>(
+                num.unsigned_abs(),
+                base,
+                alphabet,
+                pairs,
+                string,
+            )
+            .len();
+            let mut index = string.len() - written;
+
+            if num < 0 {
+                index -= 1;
+                put!(string, index, b'-');
+            }
+
+            written!(string, index)
+        }
+
+        pub const fn $core_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &[u8] {
+            match radix_from_base!(base, $type_name) {
+                Ok(radix) => $radix_function_name(num, radix, string),
+                Err(_) => panic!("unsupported base"),
+            }
+        }
+
+        pub const fn $str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($core_function_name(num, base, string)) }
+        }
+
+        pub const fn $radix_function_name(
+            num: $type_name,
+            radix: Radix,
+            string: &mut [u8],
+        ) -> &[u8] {
+            $alphabet_function_name(num, radix, &Alphabet::UPPER, string)
+        }
+
+        pub const fn $radix_str_function_name(
+            num: $type_name,
+            radix: Radix,
+            string: &mut [u8],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($radix_function_name(num, radix, string)) }
+        }
+
+        pub const fn $alphabet_function_name<'a>(
+            num: $type_name,
+            radix: Radix,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a [u8] {
+            assert!(radix.get() <= alphabet.max_base(), "unsupported base");
+
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len()
+                        > $unsigned_count_function_name(
+                            <$type_name>::MIN.unsigned_abs(),
+                            radix.get() as $unsigned_type_name
+                        )
+                );
+            }
+
+            $write_function_name(
+                num,
+                radix.get() as $unsigned_type_name,
+                alphabet,
+                as_uninit_mut(string),
+            )
+        }
+
+        pub const fn $alphabet_str_function_name<'a>(
+            num: $type_name,
+            radix: Radix,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a str {
+            unsafe {
+                core::str::from_utf8_unchecked($alphabet_function_name(
+                    num, radix, alphabet, string,
+                ))
+            }
+        }
+
+        pub const fn $in_alphabet_function_name<'a>(
+            num: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a [u8] {
+            let base = alphabet.max_base();
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len()
+                        > $unsigned_count_function_name(
+                            <$type_name>::MIN.unsigned_abs(),
+                            base as $unsigned_type_name
+                        )
+                );
+            }
+
+            $write_function_name(
+                num,
+                base as $unsigned_type_name,
+                alphabet,
+                as_uninit_mut(string),
+            )
+        }
+
+        pub const fn $in_alphabet_str_function_name<'a>(
+            num: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a str {
+            unsafe {
+                core::str::from_utf8_unchecked($in_alphabet_function_name(num, alphabet, string))
+            }
+        }
+
+        pub const fn $try_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> Result<&[u8], NumToAError> {
+            let radix = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix,
+                Err(why) => return Err(why),
+            };
+
+            let needed = $unsigned_count_function_name(
+                num.unsigned_abs(),
+                radix.get() as $unsigned_type_name,
+            ) + (num < 0) as usize;
+            if string.len() < needed {
+                return Err(NumToAError::BufferTooSmall { needed });
+            }
+
+            Ok($write_function_name(
+                num,
+                radix.get() as $unsigned_type_name,
+                &Alphabet::UPPER,
+                as_uninit_mut(string),
+            ))
+        }
+
+        pub const fn $try_str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> Result<&str, NumToAError> {
+            match $try_function_name(num, base, string) {
+                Ok(slice) => Ok(unsafe { core::str::from_utf8_unchecked(slice) }),
+                Err(why) => Err(why),
+            }
+        }
+
+        pub const fn $left_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> usize {
+            let base = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix.get() as $unsigned_type_name,
+                Err(_) => panic!("unsupported base"),
+            };
+
+            let length =
+                $unsigned_count_function_name(num.unsigned_abs(), base) + (num < 0) as usize;
+            $write_function_name(
+                num,
+                base,
+                &Alphabet::UPPER,
+                as_uninit_mut(string.split_at_mut(length).0),
+            );
+            length
+        }
+
+        pub const fn $uninit_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [MaybeUninit<u8>],
+        ) -> &[u8] {
+            let base = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix.get() as $unsigned_type_name,
+                Err(_) => panic!("unsupported base"),
+            };
+
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len()
+                        > $unsigned_count_function_name(
+                            <$type_name>::MIN.unsigned_abs(),
+                            base as $unsigned_type_name
+                        )
+                );
+            }
+
+            $write_function_name(num, base, &Alphabet::UPPER, string)
+        }
+
+        pub const fn $uninit_str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [MaybeUninit<u8>],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($uninit_function_name(num, base, string)) }
+        }
+
+        /// # Safety
+        /// `string` must be large enough to hold any value of this type in the given base, including the sign, as
+        /// given by the `REQUIRED_SPACE` constants of [crate::BaseN]. On debug builds, this is checked.
+        pub const unsafe fn $unchecked_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &[u8] {
+            let base = match radix_from_base!(base, $type_name) {
+                Ok(radix) => radix.get() as $unsigned_type_name,
+                Err(_) => panic!("unsupported base"),
+            };
+
+            if cfg!(debug_assertions) {
+                debug_assert!(
+                    string.len()
+                        > $unsigned_count_function_name(<$type_name>::MIN.unsigned_abs(), base)
+                );
+            }
+
+            let pairs = runtime_pair_lookup(base as u32, &Alphabet::UPPER);
+            $write_pairs_function_name::<true>(
+                num,
+                base,
+                &Alphabet::UPPER,
+                pairs,
+                as_uninit_mut(string),
+            )
+        }
+
+        /// # Safety
+        /// As with the above.
+        pub const unsafe fn $unchecked_str_function_name(
+            num: $type_name,
+            base: $type_name,
+            string: &mut [u8],
+        ) -> &str {
+            core::str::from_utf8_unchecked($unchecked_function_name(num, base, string))
+        }
+
+        pub const fn $digit_count_function_name(num: $type_name, radix: Radix) -> usize {
+            $unsigned_count_function_name(num.unsigned_abs(), radix.get() as $unsigned_type_name)
+                + (num < 0) as usize
+        }
+    };
+}
+
+impl_unsigned_numtoa_for!(
+    u8,
+    base_10_u8,
+    base_n,
+    write_power_of_two_u64,
+    count_digits_u64,
+    numtoa_u8,
+    numtoa_u8_str,
+    numtoa_u8_radix,
+    numtoa_u8_radix_str,
+    numtoa_u8_with_alphabet,
+    numtoa_u8_with_alphabet_str,
+    numtoa_u8_in_alphabet,
+    numtoa_u8_in_alphabet_str,
+    try_numtoa_u8,
+    try_numtoa_u8_str,
+    numtoa_u8_left,
+    numtoa_u8_uninit,
+    numtoa_u8_uninit_str,
+    numtoa_u8_unchecked,
+    numtoa_u8_unchecked_str,
+    digit_count_u8,
+    write_u8,
+    write_u8_pairs,
+    count_u8
+);
+impl_unsigned_numtoa_for!(
+    u16,
+    base_10,
+    base_n,
+    write_power_of_two_u64,
+    count_digits_u64,
+    numtoa_u16,
+    numtoa_u16_str,
+    numtoa_u16_radix,
+    numtoa_u16_radix_str,
+    numtoa_u16_with_alphabet,
+    numtoa_u16_with_alphabet_str,
+    numtoa_u16_in_alphabet,
+    numtoa_u16_in_alphabet_str,
+    try_numtoa_u16,
+    try_numtoa_u16_str,
+    numtoa_u16_left,
+    numtoa_u16_uninit,
+    numtoa_u16_uninit_str,
+    numtoa_u16_unchecked,
+    numtoa_u16_unchecked_str,
+    digit_count_u16,
+    write_u16,
+    write_u16_pairs,
+    count_u16
+);
+impl_unsigned_numtoa_for!(
+    u32,
+    base_10_u64,
+    base_n,
+    write_power_of_two_u64,
+    count_digits_u64,
+    numtoa_u32,
+    numtoa_u32_str,
+    numtoa_u32_radix,
+    numtoa_u32_radix_str,
+    numtoa_u32_with_alphabet,
+    numtoa_u32_with_alphabet_str,
+    numtoa_u32_in_alphabet,
+    numtoa_u32_in_alphabet_str,
+    try_numtoa_u32,
+    try_numtoa_u32_str,
+    numtoa_u32_left,
+    numtoa_u32_uninit,
+    numtoa_u32_uninit_str,
+    numtoa_u32_unchecked,
+    numtoa_u32_unchecked_str,
+    digit_count_u32,
+    write_u32,
+    write_u32_pairs,
+    count_u32
+);
+impl_unsigned_numtoa_for!(
+    u64,
+    base_10_u64,
+    base_n,
+    write_power_of_two_u64,
+    count_digits_u64,
+    numtoa_u64,
+    numtoa_u64_str,
+    numtoa_u64_radix,
+    numtoa_u64_radix_str,
+    numtoa_u64_with_alphabet,
+    numtoa_u64_with_alphabet_str,
+    numtoa_u64_in_alphabet,
+    numtoa_u64_in_alphabet_str,
+    try_numtoa_u64,
+    try_numtoa_u64_str,
+    numtoa_u64_left,
+    numtoa_u64_uninit,
+    numtoa_u64_uninit_str,
+    numtoa_u64_unchecked,
+    numtoa_u64_unchecked_str,
+    digit_count_u64,
+    write_u64,
+    write_u64_pairs,
+    count_u64
+);
+#[cfg(feature = "u128")]
+impl_unsigned_numtoa_for!(
+    u128,
+    base_10_u128,
+    base_n_u128,
+    write_power_of_two_u128,
+    count_digits_u128,
+    numtoa_u128,
+    numtoa_u128_str,
+    numtoa_u128_radix,
+    numtoa_u128_radix_str,
+    numtoa_u128_with_alphabet,
+    numtoa_u128_with_alphabet_str,
+    numtoa_u128_in_alphabet,
+    numtoa_u128_in_alphabet_str,
+    try_numtoa_u128,
+    try_numtoa_u128_str,
+    numtoa_u128_left,
+    numtoa_u128_uninit,
+    numtoa_u128_uninit_str,
+    numtoa_u128_unchecked,
+    numtoa_u128_unchecked_str,
+    digit_count_u128,
+    write_u128,
+    write_u128_pairs,
+    count_u128
+);
+impl_unsigned_numtoa_for!(
+    usize,
+    base_10_u64,
+    base_n,
+    write_power_of_two_u64,
+    count_digits_u64,
+    numtoa_usize,
+    numtoa_usize_str,
+    numtoa_usize_radix,
+    numtoa_usize_radix_str,
+    numtoa_usize_with_alphabet,
+    numtoa_usize_with_alphabet_str,
+    numtoa_usize_in_alphabet,
+    numtoa_usize_in_alphabet_str,
+    try_numtoa_usize,
+    try_numtoa_usize_str,
+    numtoa_usize_left,
+    numtoa_usize_uninit,
+    numtoa_usize_uninit_str,
+    numtoa_usize_unchecked,
+    numtoa_usize_unchecked_str,
+    digit_count_usize,
+    write_usize,
+    write_usize_pairs,
+    count_usize
+);
+impl_signed_numtoa_for!(
+    i8,
+    u8,
+    numtoa_i8,
+    numtoa_i8_str,
+    numtoa_i8_radix,
+    numtoa_i8_radix_str,
+    numtoa_i8_with_alphabet,
+    numtoa_i8_with_alphabet_str,
+    numtoa_i8_in_alphabet,
+    numtoa_i8_in_alphabet_str,
+    try_numtoa_i8,
+    try_numtoa_i8_str,
+    numtoa_i8_left,
+    numtoa_i8_uninit,
+    numtoa_i8_uninit_str,
+    numtoa_i8_unchecked,
+    numtoa_i8_unchecked_str,
+    digit_count_i8,
+    write_i8,
+    write_i8_pairs,
+    write_u8_pairs,
+    count_u8
+);
+impl_signed_numtoa_for!(
+    i16,
+    u16,
+    numtoa_i16,
+    numtoa_i16_str,
+    numtoa_i16_radix,
+    numtoa_i16_radix_str,
+    numtoa_i16_with_alphabet,
+    numtoa_i16_with_alphabet_str,
+    numtoa_i16_in_alphabet,
+    numtoa_i16_in_alphabet_str,
+    try_numtoa_i16,
+    try_numtoa_i16_str,
+    numtoa_i16_left,
+    numtoa_i16_uninit,
+    numtoa_i16_uninit_str,
+    numtoa_i16_unchecked,
+    numtoa_i16_unchecked_str,
+    digit_count_i16,
+    write_i16,
+    write_i16_pairs,
+    write_u16_pairs,
+    count_u16
+);
+impl_signed_numtoa_for!(
+    i32,
+    u32,
+    numtoa_i32,
+    numtoa_i32_str,
+    numtoa_i32_radix,
+    numtoa_i32_radix_str,
+    numtoa_i32_with_alphabet,
+    numtoa_i32_with_alphabet_str,
+    numtoa_i32_in_alphabet,
+    numtoa_i32_in_alphabet_str,
+    try_numtoa_i32,
+    try_numtoa_i32_str,
+    numtoa_i32_left,
+    numtoa_i32_uninit,
+    numtoa_i32_uninit_str,
+    numtoa_i32_unchecked,
+    numtoa_i32_unchecked_str,
+    digit_count_i32,
+    write_i32,
+    write_i32_pairs,
+    write_u32_pairs,
+    count_u32
+);
+impl_signed_numtoa_for!(
+    i64,
+    u64,
+    numtoa_i64,
+    numtoa_i64_str,
+    numtoa_i64_radix,
+    numtoa_i64_radix_str,
+    numtoa_i64_with_alphabet,
+    numtoa_i64_with_alphabet_str,
+    numtoa_i64_in_alphabet,
+    numtoa_i64_in_alphabet_str,
+    try_numtoa_i64,
+    try_numtoa_i64_str,
+    numtoa_i64_left,
+    numtoa_i64_uninit,
+    numtoa_i64_uninit_str,
+    numtoa_i64_unchecked,
+    numtoa_i64_unchecked_str,
+    digit_count_i64,
+    write_i64,
+    write_i64_pairs,
+    write_u64_pairs,
+    count_u64
+);
+#[cfg(feature = "u128")]
+impl_signed_numtoa_for!(
+    i128,
+    u128,
+    numtoa_i128,
+    numtoa_i128_str,
+    numtoa_i128_radix,
+    numtoa_i128_radix_str,
+    numtoa_i128_with_alphabet,
+    numtoa_i128_with_alphabet_str,
+    numtoa_i128_in_alphabet,
+    numtoa_i128_in_alphabet_str,
+    try_numtoa_i128,
+    try_numtoa_i128_str,
+    numtoa_i128_left,
+    numtoa_i128_uninit,
+    numtoa_i128_uninit_str,
+    numtoa_i128_unchecked,
+    numtoa_i128_unchecked_str,
+    digit_count_i128,
+    write_i128,
+    write_i128_pairs,
+    write_u128_pairs,
+    count_u128
+);
+impl_signed_numtoa_for!(
+    isize,
+    usize,
+    numtoa_isize,
+    numtoa_isize_str,
+    numtoa_isize_radix,
+    numtoa_isize_radix_str,
+    numtoa_isize_with_alphabet,
+    numtoa_isize_with_alphabet_str,
+    numtoa_isize_in_alphabet,
+    numtoa_isize_in_alphabet_str,
+    try_numtoa_isize,
+    try_numtoa_isize_str,
+    numtoa_isize_left,
+    numtoa_isize_uninit,
+    numtoa_isize_uninit_str,
+    numtoa_isize_unchecked,
+    numtoa_isize_unchecked_str,
+    digit_count_isize,
+    write_isize,
+    write_isize_pairs,
+    write_usize_pairs,
+    count_usize
+);
+
+#[cfg(test)]
+mod core_test {
+
+    use super::*;
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_required_sizes_for_base(
+        base: u128,
         expected_space_u8: usize,
         expected_space_u16: usize,
         expected_space_u32: usize,
@@ -336,102 +1753,901 @@ mod core_test {
         expected_space_i128: usize,
     ) {
         assert_eq!(
-            expected_space_u8,
-            required_space(base, u8::MAX as u128, false)
+            expected_space_u8,
+            required_space(base, u8::MAX as u128, false)
+        );
+        assert_eq!(
+            expected_space_u16,
+            required_space(base, u16::MAX as u128, false)
+        );
+        assert_eq!(
+            expected_space_u32,
+            required_space(base, u32::MAX as u128, false)
+        );
+        assert_eq!(
+            expected_space_u64,
+            required_space(base, u64::MAX as u128, false)
+        );
+        assert_eq!(expected_space_u128, required_space(base, u128::MAX, false));
+        assert_eq!(
+            expected_space_i8,
+            required_space(base, i8::MIN.unsigned_abs() as u128, true)
+        );
+        assert_eq!(
+            expected_space_i16,
+            required_space(base, i16::MIN.unsigned_abs() as u128, true)
+        );
+        assert_eq!(
+            expected_space_i32,
+            required_space(base, i32::MIN.unsigned_abs() as u128, true)
+        );
+        assert_eq!(
+            expected_space_i64,
+            required_space(base, i64::MIN.unsigned_abs() as u128, true)
+        );
+        assert_eq!(
+            expected_space_i128,
+            required_space(base, i128::MIN.unsigned_abs(), true)
+        );
+    }
+
+    #[test]
+    fn sanity_check_required_size() {
+        // test zero
+        assert_eq!(1, required_space(2, 0, false));
+        // test positive one
+        assert_eq!(1, required_space(2, 1, false));
+        // test negative one
+        assert_eq!(2, required_space(2, 1, true));
+
+        verify_required_sizes_for_base(2, 8, 16, 32, 64, 128, 9, 17, 33, 65, 129);
+
+        verify_required_sizes_for_base(
+            8, 3,  // 377
+            6,  // 177777
+            11, // 37777777777
+            22, // 1777777777777777777777
+            43, // 3777777777777777777777777777777777777777777
+            4,  // -200
+            7,  // -100000
+            12, // -20000000000
+            23, // -1000000000000000000000
+            44, // -2000000000000000000000000000000000000000000
+        );
+
+        verify_required_sizes_for_base(
+            10, 3,  // 255
+            5,  // 65535
+            10, // 4294967295
+            20, // 18446744073709551615
+            39, // 340282366920938463463374607431768211455
+            4,  // -128
+            6,  // -32768
+            11, // -2147483648
+            20, // -9223372036854775808
+            40, // -170141183460469231731687303715884105728
+        );
+
+        verify_required_sizes_for_base(16, 2, 4, 8, 16, 32, 3, 5, 9, 17, 33);
+    }
+
+    #[test]
+    fn sanity() {
+        assert_eq!(b"256123", numtoa_i32(256123_i32, 10, &mut [0u8; 20]));
+    }
+
+    #[test]
+    fn try_sanity() {
+        assert_eq!(
+            Ok(&b"256123"[..]),
+            try_numtoa_i32(256123_i32, 10, &mut [0u8; 6])
+        );
+        assert_eq!(Ok("-80"), try_numtoa_i8_str(i8::MIN, 16, &mut [0u8; 3]));
+        assert_eq!(Ok("11111111"), try_numtoa_u8_str(u8::MAX, 2, &mut [0u8; 8]));
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn try_buffer_too_small() {
+        assert_eq!(
+            Err(NumToAError::BufferTooSmall { needed: 6 }),
+            try_numtoa_i32(256123_i32, 10, &mut [0u8; 5])
+        );
+        assert_eq!(
+            Err(NumToAError::BufferTooSmall { needed: 7 }),
+            try_numtoa_i32(-256123_i32, 10, &mut [0u8; 6])
+        );
+        assert_eq!(
+            Err(NumToAError::BufferTooSmall { needed: 1 }),
+            try_numtoa_u8(0, 10, &mut [])
+        );
+        assert_eq!(
+            Err(NumToAError::BufferTooSmall { needed: 129 }),
+            try_numtoa_i128_str(i128::MIN, 2, &mut [0u8; 128])
+        );
+    }
+
+    #[test]
+    fn try_unsupported_base() {
+        assert_eq!(
+            Err(NumToAError::UnsupportedBase),
+            try_numtoa_i32(50, 1, &mut [0u8; 100])
+        );
+        assert_eq!(
+            Err(NumToAError::UnsupportedBase),
+            try_numtoa_i32(50, -10, &mut [0u8; 100])
+        );
+        assert_eq!(
+            Err(NumToAError::UnsupportedBase),
+            try_numtoa_u64(50, 0, &mut [0u8; 100])
+        );
+        assert_eq!(
+            Err(NumToAError::UnsupportedBase),
+            try_numtoa_u8_str(36, 37, &mut [0u8; 100])
+        );
+    }
+
+    #[test]
+    fn try_const_context() {
+        const LEN: usize = match try_numtoa_u32(12345, 10, &mut [0u8; 5]) {
+            Ok(slice) => slice.len(),
+            Err(_) => 0,
+        };
+        const ERROR: Result<usize, NumToAError> = match try_numtoa_u32(12345, 10, &mut [0u8; 4]) {
+            Ok(slice) => Ok(slice.len()),
+            Err(why) => Err(why),
+        };
+        assert_eq!(5, LEN);
+        assert_eq!(Err(NumToAError::BufferTooSmall { needed: 5 }), ERROR);
+    }
+
+    #[test]
+    fn try_matches_numtoa() {
+        let mut expected = [0u8; 64];
+        let mut actual = [0u8; 64];
+        for base in 2..=36 {
+            for i in i16::MIN..=i16::MAX {
+                assert_eq!(
+                    numtoa_i16(i, base, &mut expected),
+                    try_numtoa_i16(i, base, &mut actual).unwrap()
+                );
+            }
+            for i in [0, 1, u64::MAX / 3, u64::MAX] {
+                assert_eq!(
+                    numtoa_u64(i, base as u64, &mut expected),
+                    try_numtoa_u64(i, base as u64, &mut actual).unwrap()
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn left_matches_numtoa() {
+        let mut expected = [0u8; 130];
+        let mut actual = [0u8; 130];
+        for base in 2..=36 {
+            for i in i16::MIN..=i16::MAX {
+                let length = numtoa_i16_left(i, base, &mut actual);
+                assert_eq!(numtoa_i16(i, base, &mut expected), &actual[..length]);
+            }
+            for i in [0, 1, u64::MAX / 3, u64::MAX] {
+                let length = numtoa_u64_left(i, base as u64, &mut actual);
+                assert_eq!(numtoa_u64(i, base as u64, &mut expected), &actual[..length]);
+            }
+            for i in [i128::MIN, -1, 0, i128::MAX] {
+                let length = numtoa_i128_left(i, base as i128, &mut actual);
+                assert_eq!(
+                    numtoa_i128(i, base as i128, &mut expected),
+                    &actual[..length]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn left_appends_in_place() {
+        let mut line = [b'.'; 16];
+        let mut length = numtoa_u8_left(7, 10, &mut line);
+        length += numtoa_i32_left(-42, 10, &mut line[length..]);
+        length += numtoa_usize_left(255, 16, &mut line[length..]);
+        assert_eq!(&line[..length], b"7-42FF");
+        assert_eq!(&line[length..], b"..........");
+
+        // Only the bytes needed for the value are required.
+        assert_eq!(numtoa_u64_left(u64::MAX, 10, &mut [0u8; 20]), 20);
+        assert_eq!(numtoa_i64_left(-9, 10, &mut [0u8; 2]), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn left_buffer_too_small() {
+        numtoa_u32_left(1000, 10, &mut [0u8; 3]);
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn uninit_matches_numtoa() {
+        let mut expected = [0u8; 130];
+        let mut actual = [MaybeUninit::uninit(); 130];
+        for base in 2..=36 {
+            for i in i16::MIN..=i16::MAX {
+                assert_eq!(
+                    numtoa_i16(i, base, &mut expected),
+                    numtoa_i16_uninit(i, base, &mut actual)
+                );
+            }
+            for i in [0, 1, u128::MAX / 3, u128::MAX] {
+                assert_eq!(
+                    numtoa_u128(i, base as u128, &mut expected),
+                    numtoa_u128_uninit(i, base as u128, &mut actual)
+                );
+            }
+        }
+        assert_eq!(numtoa_u8_uninit_str(255, 10, &mut actual[..3]), "255");
+    }
+
+    // Compares the unchecked functions of each type with the checked ones in every base, writing into a buffer of
+    // exactly the required size whose unwritten bytes must be left untouched.
+    macro_rules! verify_unchecked {
+        ($($type_name:ty, $core_function_name:ident, $unchecked_function_name:ident);*) => {
+            let mut expected = [0u8; 130];
+            let mut actual = [b'.'; 130];
+            for base in 2..=36 {
+                $(
+                    let space = $core_function_name(<$type_name>::MIN, base as $type_name, &mut expected)
+                        .len()
+                        .max($core_function_name(<$type_name>::MAX, base as $type_name, &mut expected).len());
+                    let mut num = base as u64;
+                    let mut values = [<$type_name>::MIN, <$type_name>::MAX, 0, 1, 0, 0, 0, 0, 0, 0];
+                    for _ in 0..200 {
+                        for value in values.iter_mut().skip(4) {
+                            num = num.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                            *value = (num >> (num % 64)) as $type_name;
+                        }
+                        for value in values.iter().copied() {
+                            actual.fill(b'.');
+                            let written = unsafe {
+                                $unchecked_function_name(value, base as $type_name, &mut actual[..space])
+                            };
+                            assert_eq!($core_function_name(value, base as $type_name, &mut expected), written);
+                            let length = written.len();
+                            assert!(actual[..space - length].iter().all(|&byte| byte == b'.'));
+                            assert!(actual[space..].iter().all(|&byte| byte == b'.'));
+                        }
+                    }
+                )*
+            }
+        };
+    }
+
+    #[test]
+    fn unchecked_matches_numtoa() {
+        verify_unchecked!(
+            u8, numtoa_u8, numtoa_u8_unchecked;
+            i8, numtoa_i8, numtoa_i8_unchecked;
+            u16, numtoa_u16, numtoa_u16_unchecked;
+            i16, numtoa_i16, numtoa_i16_unchecked;
+            u32, numtoa_u32, numtoa_u32_unchecked;
+            i32, numtoa_i32, numtoa_i32_unchecked;
+            u64, numtoa_u64, numtoa_u64_unchecked;
+            i64, numtoa_i64, numtoa_i64_unchecked;
+            usize, numtoa_usize, numtoa_usize_unchecked;
+            isize, numtoa_isize, numtoa_isize_unchecked
+        );
+        #[cfg(feature = "u128")]
+        verify_unchecked!(
+            u128, numtoa_u128, numtoa_u128_unchecked;
+            i128, numtoa_i128, numtoa_i128_unchecked
+        );
+    }
+
+    #[test]
+    fn unchecked_exhaustive_16_bit() {
+        let mut expected = [0u8; 17];
+        let mut actual = [0u8; 17];
+        for base in 2..=36 {
+            for i in i16::MIN..=i16::MAX {
+                assert_eq!(numtoa_i16(i, base, &mut expected), unsafe {
+                    numtoa_i16_unchecked(i, base, &mut actual)
+                });
+                assert_eq!(numtoa_u16(i as u16, base as u16, &mut expected), unsafe {
+                    numtoa_u16_unchecked(i as u16, base as u16, &mut actual[1..])
+                });
+            }
+        }
+        assert_eq!(
+            unsafe { numtoa_u8_unchecked_str(255, 10, &mut actual[..3]) },
+            "255"
+        );
+    }
+
+    #[test]
+    fn unchecked_const_context() {
+        const BUFFER: [u8; 4] = {
+            let mut buffer = [0u8; 4];
+            unsafe { numtoa_i8_unchecked(-128, 10, &mut buffer) };
+            buffer
+        };
+        assert_eq!(&BUFFER, b"-128");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn unchecked_array_too_small() {
+        let _ = unsafe { numtoa_u16_unchecked(0, 10, &mut [0u8; 4]) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn unchecked_unsupported_base() {
+        let _ = unsafe { numtoa_u32_unchecked(1, 37, &mut [0u8; 64]) };
+    }
+
+    #[test]
+    fn uninit_const_context() {
+        const BUFFER: [u8; 4] = {
+            let mut buffer = [MaybeUninit::uninit(); 4];
+            let written = numtoa_i8_uninit(-128, 10, &mut buffer);
+            [written[0], written[1], written[2], written[3]]
+        };
+        assert_eq!(&BUFFER, b"-128");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn uninit_array_too_small() {
+        let _ = numtoa_u16_uninit(0, 10, &mut [MaybeUninit::uninit(); 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn base_too_low() {
+        numtoa_i32(50, 1, &mut [0u8; 100]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn base_too_high() {
+        numtoa_i32(36, 37, &mut [0u8; 100]);
+    }
+
+    #[test]
+    fn radix_matches_base() {
+        let mut expected = [0u8; 64];
+        let mut actual = [0u8; 64];
+        for base in 2..=36 {
+            let radix = Radix::new(base);
+            for i in [0, 1, 35, u64::MAX / 7, u64::MAX] {
+                assert_eq!(
+                    numtoa_u64(i, base as u64, &mut expected),
+                    numtoa_u64_radix(i, radix, &mut actual)
+                );
+            }
+            for i in [i32::MIN, -1, 0, 1, i32::MAX] {
+                assert_eq!(
+                    numtoa_i32_str(i, base as i32, &mut expected),
+                    numtoa_i32_radix_str(i, radix, &mut actual)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lowercase_alphabet() {
+        let mut upper = [0u8; 64];
+        let mut lower = [0u8; 64];
+        for base in 2..=36 {
+            let radix = Radix::new(base);
+            for i in [0, 1, 35, u64::MAX / 7, u64::MAX] {
+                assert_eq!(
+                    numtoa_u64_radix_str(i, radix, &mut upper).to_ascii_lowercase(),
+                    numtoa_u64_with_alphabet_str(i, radix, &Alphabet::LOWER, &mut lower)
+                );
+            }
+        }
+    }
+
+    // Writes `num` by repeated division, to check the shift and mask paths against.
+    fn divide(mut num: u128, base: u128, alphabet: &Alphabet, string: &mut [u8]) -> usize {
+        let mut index = string.len();
+        loop {
+            index -= 1;
+            string[index] = alphabet.digit((num % base) as usize);
+            num /= base;
+            if num == 0 {
+                return index;
+            }
+        }
+    }
+
+    // Checks conversions with the pair lookup table of each base against one digit at a time.
+    macro_rules! verify_pair_lookups {
+        ($($base:expr, $alphabet:expr);*) => {
+            let mut expected = [0u8; 129];
+            let mut actual = [0u8; 129];
+            $(
+                let pairs = pair_lookup::<{ 2 * $base * $base }>($base, &$alphabet);
+                let mut num = 1u64;
+                for i in 0..2000u64 {
+                    num = num.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    let value = num >> (num % 64);
+                    let index = divide(value as u128, $base, &$alphabet, &mut expected);
+                    let written = unsafe { write_u64_pairs::<false>(value, $base, &$alphabet, &pairs, as_uninit_mut(&mut actual)) };
+                    assert_eq!(&expected[index..], written);
+
+                    let index = divide(i as u8 as u128, $base, &$alphabet, &mut expected);
+                    let written = unsafe { write_u8_pairs::<false>(i as u8, $base, &$alphabet, &pairs, as_uninit_mut(&mut actual)) };
+                    assert_eq!(&expected[index..], written);
+
+                    let written = unsafe { write_i64_pairs::<false>(-((value >> 1) as i64) - 1, $base, &$alphabet, &pairs, as_uninit_mut(&mut actual)) };
+                    let index = divide((value >> 1) as u128 + 1, $base, &$alphabet, &mut expected);
+                    assert_eq!(b'-', written[0]);
+                    assert_eq!(&expected[index..], &written[1..]);
+
+                    #[cfg(feature = "u128")]
+                    {
+                        let value = (value as u128) << 64 | num as u128;
+                        let index = divide(value, $base, &$alphabet, &mut expected);
+                        let written = unsafe { write_u128_pairs::<false>(value, $base, &$alphabet, &pairs, as_uninit_mut(&mut actual)) };
+                        assert_eq!(&expected[index..], written);
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn pair_lookups() {
+        verify_pair_lookups!(
+            3, Alphabet::UPPER;
+            5, Alphabet::UPPER;
+            7, Alphabet::UPPER;
+            9, Alphabet::LOWER;
+            10, Alphabet::BASE58;
+            12, Alphabet::LOWER;
+            17, Alphabet::UPPER;
+            35, Alphabet::UPPER;
+            36, Alphabet::UPPER;
+            58, Alphabet::BASE58;
+            62, Alphabet::BASE62;
+            85, Alphabet::Z85
         );
+        assert_eq!(&pair_lookup::<512>(16, &Alphabet::LOWER)[..8], b"00010203");
         assert_eq!(
-            expected_space_u16,
-            required_space(base, u16::MAX as u128, false)
+            &pair_lookup::<18>(3, &Alphabet::UPPER),
+            b"000102101112202122"
         );
+    }
+
+    #[cfg(all(feature = "fast", not(feature = "small")))]
+    #[test]
+    fn runtime_pair_lookups() {
         assert_eq!(
-            expected_space_u32,
-            required_space(base, u32::MAX as u128, false)
+            runtime_pair_lookup(7, &Alphabet::UPPER),
+            &pair_lookup::<98>(7, &Alphabet::UPPER)
         );
         assert_eq!(
-            expected_space_u64,
-            required_space(base, u64::MAX as u128, false)
+            runtime_pair_lookup(36, &Alphabet::BASE62),
+            &pair_lookup::<2592>(36, &Alphabet::UPPER)
         );
+        assert_eq!(runtime_pair_lookup(10, &Alphabet::LOWER), DEC_LOOKUP);
+        assert!(runtime_pair_lookup(11, &Alphabet::LOWER).is_empty());
+        assert!(runtime_pair_lookup(58, &Alphabet::BASE62).is_empty());
+        assert!(runtime_pair_lookup(3, &Alphabet::BASE58).is_empty());
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn u128_chunks() {
+        assert_eq!((4_738_381_338_321_616_896, 12), chunk_power(36));
+        assert_eq!((12_157_665_459_056_928_801, 40), chunk_power(3));
+        assert_eq!((10_000_000_000_000_000_000, 19), chunk_power(10));
+
+        let mut expected = [0u8; 129];
+        let mut actual = [0u8; 129];
+        for (base, alphabet) in [
+            (3, Alphabet::UPPER),
+            (7, Alphabet::LOWER),
+            (12, Alphabet::UPPER),
+            (36, Alphabet::UPPER),
+            (58, Alphabet::BASE58),
+            (85, Alphabet::Z85),
+        ] {
+            let power = chunk_power(base as u64).0 as u128;
+            for value in [
+                u64::MAX as u128 + 1,
+                power * power - 1,
+                power * power,
+                power * power + 1,
+                power * (power - 1),
+                u128::MAX - 1,
+                u128::MAX,
+                0xDEAD_BEEF_0000_0000_0000_0001_0000_0000,
+            ] {
+                let index = divide(value, base, &alphabet, &mut expected);
+                let written = write_u128(value, base, &alphabet, as_uninit_mut(&mut actual));
+                assert_eq!(&expected[index..], written);
+                let written = match Radix::try_new(base as u32) {
+                    Ok(radix) => numtoa_u128_with_alphabet(value, radix, &alphabet, &mut actual),
+                    Err(_) => numtoa_u128_in_alphabet(value, &alphabet, &mut actual),
+                };
+                assert_eq!(&expected[index..], written);
+            }
+        }
         assert_eq!(
-            expected_space_u128,
-            required_space(base, u128::MAX as u128, false)
+            b"-7KSYYIZZKUTUDZBV8AQZTECJK",
+            numtoa_i128(i128::MIN, 36, &mut actual)
         );
         assert_eq!(
-            expected_space_i8,
-            required_space(base, i8::MIN.unsigned_abs() as u128, true)
+            "7KSYYIZZKUTUDZBV8AQZTECJJ",
+            crate::BaseN::<36>::u128(i128::MAX as u128).as_str()
         );
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn power_of_two_bases() {
+        let mut expected = [0u8; 129];
+        let mut actual = [0u8; 129];
+        let mut num = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        for base in [2, 4, 8, 16, 32] {
+            for alphabet in [Alphabet::UPPER, Alphabet::LOWER, Alphabet::BASE64URL] {
+                let radix = Radix::new(base);
+                for i in [
+                    0,
+                    1,
+                    0xF,
+                    0x10,
+                    0xFF,
+                    0x100,
+                    0xFFF,
+                    u64::MAX as u128,
+                    u128::MAX,
+                    num,
+                ] {
+                    let index = divide(i, base as u128, &alphabet, &mut expected);
+                    assert_eq!(
+                        &expected[index..],
+                        numtoa_u128_with_alphabet(i, radix, &alphabet, &mut actual)
+                    );
+                    let index = divide(i as u64 as u128, base as u128, &alphabet, &mut expected);
+                    assert_eq!(
+                        &expected[index..],
+                        numtoa_u64_with_alphabet(i as u64, radix, &alphabet, &mut actual)
+                    );
+                    let index = divide(i as u8 as u128, base as u128, &alphabet, &mut expected);
+                    assert_eq!(
+                        &expected[index..],
+                        numtoa_u8_with_alphabet(i as u8, radix, &alphabet, &mut actual)
+                    );
+                    num = num.rotate_left(13) ^ i;
+                }
+            }
+        }
+
+        let index = divide(num, 64, &Alphabet::BASE64URL, &mut expected);
         assert_eq!(
-            expected_space_i16,
-            required_space(base, i16::MIN.unsigned_abs() as u128, true)
+            &expected[index..],
+            numtoa_u128_in_alphabet(num, &Alphabet::BASE64URL, &mut actual)
         );
+        assert_eq!("-80", numtoa_i8_str(i8::MIN, 16, &mut actual));
         assert_eq!(
-            expected_space_i32,
-            required_space(base, i32::MIN.unsigned_abs() as u128, true)
+            "-8000000000000000",
+            numtoa_i64_str(i64::MIN, 16, &mut actual)
         );
+        assert_eq!("-1", numtoa_i128_str(-1, 2, &mut actual));
         assert_eq!(
-            expected_space_i64,
-            required_space(base, i64::MIN.unsigned_abs() as u128, true)
+            "7VVVVVVVVVVVVVVVVVVVVVVVVV",
+            numtoa_u128_str(u128::MAX, 32, &mut actual)
         );
         assert_eq!(
-            expected_space_i128,
-            required_space(base, i128::MIN.unsigned_abs() as u128, true)
+            "deadbeef",
+            crate::BaseN::<16>::u32_with_alphabet(0xDEADBEEF, &Alphabet::LOWER).as_str()
         );
     }
 
+    #[cfg(feature = "u128")]
     #[test]
-    fn sanity_check_required_size() {
-        // test zero
-        assert_eq!(1, required_space(2, 0, false));
-        // test positive one
-        assert_eq!(1, required_space(2, 1, false));
-        // test negative one
-        assert_eq!(2, required_space(2, 1, true));
+    fn u128_decimal_chunks() {
+        let mut expected = [0u8; 40];
+        let mut actual = [0u8; 40];
+        let pow10_19 = POW10_19 as u128;
+        let mut num = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        for i in [
+            u64::MAX as u128,
+            u64::MAX as u128 + 1,
+            pow10_19 * pow10_19,
+            pow10_19 * pow10_19 - 1,
+            pow10_19 * pow10_19 + 7,
+            pow10_19 * 3 + 1,
+            (1 << 83) - 1,
+            1 << 83,
+            u128::MAX,
+        ]
+        .iter()
+        .copied()
+        .chain((0..1000).map(|_| {
+            num = num.rotate_left(29).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            num >> (num % 64)
+        })) {
+            let index = divide(i, 10, &Alphabet::UPPER, &mut expected);
+            assert_eq!(&expected[index..], numtoa_u128(i, 10, &mut actual));
+            assert_eq!(div_rem_pow10_19(i), (i / pow10_19, (i % pow10_19) as u64));
+        }
 
-        verify_required_sizes_for_base(2, 8, 16, 32, 64, 128, 9, 17, 33, 65, 129);
+        let min = const { crate::BaseN::<10>::i128(i128::MIN) };
+        assert_eq!("-170141183460469231731687303715884105728", min.as_str());
+    }
 
-        verify_required_sizes_for_base(
-            8, 3,  // 377
-            6,  // 177777
-            11, // 37777777777
-            22, // 1777777777777777777777
-            43, // 3777777777777777777777777777777777777777777
-            4,  // -200
-            7,  // -100000
-            12, // -20000000000
-            23, // -1000000000000000000000
-            44, // -2000000000000000000000000000000000000000000
+    #[cfg(feature = "u128")]
+    #[test]
+    fn digit_count_matches_numtoa() {
+        let mut buffer = [0u8; 129];
+        let mut num = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        for base in 2..=36 {
+            let radix = Radix::new(base);
+            for _ in 0..200 {
+                num = num.rotate_left(29).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+                let i = num >> (num % 128);
+                assert_eq!(
+                    numtoa_u128(i, base as u128, &mut buffer).len(),
+                    digit_count_u128(i, radix)
+                );
+                assert_eq!(
+                    numtoa_i64(i as i64, base as i64, &mut buffer).len(),
+                    digit_count_i64(i as i64, radix)
+                );
+                assert_eq!(
+                    numtoa_u16(i as u16, base as u16, &mut buffer).len(),
+                    digit_count_u16(i as u16, radix)
+                );
+            }
+        }
+
+        let mut power = 1u128;
+        while let Some(next) = power.checked_mul(10) {
+            assert_eq!(
+                digit_count_u128(power - 1, Radix::DEC),
+                numtoa_u128(power - 1, 10, &mut buffer).len()
+            );
+            assert_eq!(
+                digit_count_u128(power, Radix::DEC),
+                numtoa_u128(power, 10, &mut buffer).len()
+            );
+            power = next;
+        }
+        for i in i8::MIN..=i8::MAX {
+            assert_eq!(
+                numtoa_i8(i, 10, &mut buffer).len(),
+                digit_count_i8(i, Radix::DEC)
+            );
+            assert_eq!(
+                numtoa_i8(i, 8, &mut buffer).len(),
+                digit_count_i8(i, Radix::OCT)
+            );
+        }
+        assert_eq!(40, digit_count_i128(i128::MIN, Radix::DEC));
+        assert_eq!(129, digit_count_i128(i128::MIN, Radix::BIN));
+        assert_eq!(1, digit_count_u64(0, Radix::new(36)));
+    }
+
+    // Writes a number with one of the base 10 algorithms, whichever table profile is enabled.
+    macro_rules! write_base_10_with {
+        ($algorithm:ident, $num:expr, $buffer:expr) => {{
+            const UNCHECKED: bool = false;
+            let mut num = $num;
+            let string = as_uninit_mut($buffer);
+            let mut index = string.len() - 1;
+            $algorithm!(num, index, string);
+            written!(string, index.wrapping_add(1))
+        }};
+    }
+
+    #[test]
+    fn table_profiles() {
+        let mut digits = [0u8; 20];
+        let mut pairs = [0u8; 20];
+        let mut quads = [0u8; 20];
+        for num in 0..=u16::MAX {
+            let expected = write_base_10_with!(base_10_digits, num, &mut digits);
+            assert_eq!(
+                expected,
+                write_base_10_with!(base_10_pairs, num, &mut pairs)
+            );
+            assert_eq!(
+                expected,
+                write_base_10_with!(base_10_quads, num, &mut quads)
+            );
+        }
+
+        let mut num = 1u64;
+        for _ in 0..10000 {
+            // A linear congruential generator, to cover every digit count and every digit.
+            num = num
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            for value in [num, num >> (num % 64), u64::MAX - num % 1000] {
+                let expected = write_base_10_with!(base_10_digits, value, &mut digits);
+                assert_eq!(
+                    expected,
+                    write_base_10_with!(base_10_pairs, value, &mut pairs)
+                );
+                assert_eq!(
+                    expected,
+                    write_base_10_with!(base_10_quads, value, &mut quads)
+                );
+            }
+        }
+        assert_eq!(
+            b"18446744073709551615",
+            write_base_10_with!(base_10_quads, u64::MAX, &mut quads)
         );
+    }
 
-        verify_required_sizes_for_base(
-            10, 3,  // 255
-            5,  // 65535
-            10, // 4294967295
-            20, // 18446744073709551615
-            39, // 340282366920938463463374607431768211455
-            4,  // -128
-            6,  // -32768
-            11, // -2147483648
-            20, // -9223372036854775808
-            40, // -170141183460469231731687303715884105728
+    #[test]
+    fn swar_decimal() {
+        for (num, digits) in [
+            (0, b"00000000"),
+            (12345678, b"12345678"),
+            (99999999, b"99999999"),
+            (1000, b"00001000"),
+        ] {
+            assert_eq!(digits, &swar_8_digits(num).to_le_bytes());
+        }
+
+        let mut expected = [0u8; 20];
+        let mut actual = [0u8; 20];
+        let mut power = 1u64;
+        while let Some(next) = power.checked_mul(10) {
+            for i in [power - 1, power, power + 1, power * 9 + power / 2] {
+                let index = divide(i as u128, 10, &Alphabet::UPPER, &mut expected);
+                assert_eq!(&expected[index..], numtoa_u64(i, 10, &mut actual));
+                let index = divide(i as u32 as u128, 10, &Alphabet::UPPER, &mut expected);
+                assert_eq!(&expected[index..], numtoa_u32(i as u32, 10, &mut actual));
+            }
+            power = next;
+        }
+        assert_eq!(
+            b"18446744073709551615",
+            numtoa_u64(u64::MAX, 10, &mut actual)
         );
+        assert_eq!(b"4294967295", numtoa_u32(u32::MAX, 10, &mut actual));
+        assert_eq!(
+            b"-9223372036854775808",
+            numtoa_i64(i64::MIN, 10, &mut actual)
+        );
+    }
 
-        verify_required_sizes_for_base(16, 2, 4, 8, 16, 32, 3, 5, 9, 17, 33);
+    #[test]
+    fn reciprocal_division() {
+        let mut num = 0x9E37_79B9_7F4A_7C15u64;
+        for i in [
+            0,
+            1,
+            9999,
+            10000,
+            u32::MAX as u64,
+            u32::MAX as u64 + 1,
+            u64::MAX,
+        ]
+        .iter()
+        .copied()
+        .chain((0..10000).map(|_| {
+            num ^= num << 13;
+            num ^= num >> 7;
+            num ^= num << 17;
+            num >> (num % 64)
+        })) {
+            let (low, high) = (i as u32, (i >> 32) as u32);
+            assert_eq!(
+                ((low as u64 * high as u64) >> 32) as u32,
+                mul_high_u32(low, high)
+            );
+            assert_eq!((i / 10000, (i % 10000) as u32), div_rem_10000_u64(i));
+            assert_eq!((low / 10000, low % 10000), div_rem_10000_u32(low));
+            assert_eq!((low / 100, low % 100), div_rem_100_u32(low));
+        }
     }
 
     #[test]
-    fn sanity() {
-        assert_eq!(b"256123", numtoa_i32(256123_i32, 10, &mut [0u8; 20]));
+    fn custom_alphabet() {
+        const SHIFTED: Alphabet = Alphabet::new(b"123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ0");
+        let mut buffer = [0u8; 20];
+        assert_eq!(
+            "1",
+            numtoa_u32_with_alphabet_str(0, Radix::DEC, &SHIFTED, &mut buffer)
+        );
+        assert_eq!(
+            "-A1A2",
+            numtoa_i16_with_alphabet_str(-9091, Radix::DEC, &SHIFTED, &mut buffer)
+        );
+        assert_eq!(
+            "GG",
+            numtoa_u8_with_alphabet_str(255, Radix::HEX, &SHIFTED, &mut buffer)
+        );
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn in_alphabet() {
+        let mut buffer = [0u8; 64];
+        assert_eq!(
+            "jpXCZedGfVQ",
+            numtoa_u64_in_alphabet_str(u64::MAX, &Alphabet::BASE58, &mut buffer)
+        );
+        assert_eq!(
+            "YcVfxkQb6JRzqk5kF2tNLv",
+            numtoa_u128_in_alphabet_str(u128::MAX, &Alphabet::BASE58, &mut buffer)
+        );
+        assert_eq!(
+            "1LY7VK",
+            numtoa_u32_in_alphabet_str(1234567890, &Alphabet::BASE62, &mut buffer)
+        );
+        assert_eq!(
+            "-IAAAAAAAAAA",
+            numtoa_i64_in_alphabet_str(i64::MIN, &Alphabet::BASE64URL, &mut buffer)
+        );
+        assert_eq!(
+            "nToCa",
+            numtoa_u32_in_alphabet_str(1234567890, &Alphabet::Z85, &mut buffer)
+        );
+        assert_eq!(
+            "$!",
+            numtoa_u8_in_alphabet_str(255, &Alphabet::ASCII85, &mut buffer)
+        );
+        assert_eq!(
+            "!",
+            numtoa_i16_in_alphabet_str(0, &Alphabet::ASCII85, &mut buffer)
+        );
+    }
+
+    #[test]
+    fn in_alphabet_matches_radix() {
+        let mut expected = [0u8; 64];
+        let mut actual = [0u8; 64];
+        let octal = Alphabet::from_digits(b"01234567");
+        let decimal = Alphabet::from_digits(b"0123456789");
+        for i in [0, 1, 7, 8, 9, 10, 99, 100, u64::MAX / 7, u64::MAX] {
+            assert_eq!(
+                numtoa_u64_radix(i, Radix::OCT, &mut expected),
+                numtoa_u64_in_alphabet(i, &octal, &mut actual)
+            );
+            assert_eq!(
+                numtoa_u64_radix(i, Radix::DEC, &mut expected),
+                numtoa_u64_in_alphabet(i, &decimal, &mut actual)
+            );
+            assert_eq!(
+                numtoa_u64_radix(i, Radix::MAX, &mut expected),
+                numtoa_u64_in_alphabet(i, &Alphabet::UPPER, &mut actual)
+            );
+        }
     }
 
     #[test]
     #[should_panic]
-    fn base_too_low() {
-        numtoa_i32(50, 1, &mut [0u8; 100]);
+    fn alphabet_too_small_for_radix() {
+        let octal = Alphabet::from_digits(b"01234567");
+        let _ = numtoa_u32_with_alphabet(0, Radix::DEC, &octal, &mut [0u8; 10]);
+    }
+
+    #[test]
+    fn required_space_beyond_base36() {
+        assert_eq!(11, required_space(58, u64::MAX as u128, false));
+        assert_eq!(22, required_space(62, u128::MAX, false));
+        assert_eq!(
+            12,
+            required_space(64, i64::MIN.unsigned_abs() as u128, true)
+        );
+        assert_eq!(21, required_space(85, i128::MIN.unsigned_abs(), true));
     }
 
     #[test]
     #[should_panic]
-    fn base_too_high() {
-        numtoa_i32(36, 37, &mut [0u8; 100]);
+    fn negative_base() {
+        numtoa_i64(50, -10, &mut [0u8; 100]);
     }
 
     #[test]
@@ -0,0 +1,48 @@
+use core::fmt::{self, Display, Formatter};
+
+/// The reasons why a fallible `try_numtoa` conversion may fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumToAError {
+    /// The supplied buffer cannot hold the converted number, which requires `needed` bytes.
+    BufferTooSmall { needed: usize },
+    /// The requested base is outside of the supported range of 2 to 36.
+    UnsupportedBase,
+}
+
+impl Display for NumToAError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            NumToAError::BufferTooSmall { needed } => {
+                write!(f, "buffer too small: {} bytes are needed", needed)
+            }
+            NumToAError::UnsupportedBase => f.write_str("unsupported base"),
+        }
+    }
+}
+
+impl core::error::Error for NumToAError {}
+
+/// The reasons why parsing an integer with `atonum` may fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AToNumError {
+    /// The input does not contain any digits.
+    Empty,
+    /// The byte at `index` is not a digit of the requested base.
+    InvalidDigit { index: usize },
+    /// The value is too large or too small to be represented by the integer type.
+    Overflow,
+}
+
+impl Display for AToNumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            AToNumError::Empty => f.write_str("cannot parse integer from empty string"),
+            AToNumError::InvalidDigit { index } => write!(f, "invalid digit at index {}", index),
+            AToNumError::Overflow => {
+                f.write_str("number too large or too small to fit in target type")
+            }
+        }
+    }
+}
+
+impl core::error::Error for AToNumError {}
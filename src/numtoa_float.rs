@@ -0,0 +1,904 @@
+use core::mem::MaybeUninit;
+
+use crate::bignum::{Big, POW10_9};
+use crate::numtoa_alphabet::Alphabet;
+use crate::numtoa_core::{as_uninit_mut, assume_init, write_u64};
+use crate::numtoa_error::NumToAError;
+use crate::ryu::{d2d, decimal_length, f2d, Decimal, Float};
+
+/// The largest number of bytes needed to write any `f32`, such as `-1.1754944e-38`.
+///
+/// Floats are written in the shortest form which parses back to the same value, using the same notation as their
+/// `Debug` implementation: numbers from `1e-4` up to `1e16` are written as decimals such as `0.3` or `100.0`, and
+/// all other numbers in exponential notation such as `1e16` or `-1.5e-7`. The special values are written as `NaN`,
+/// `inf` and `-inf`.
+pub const REQUIRED_SPACE_F32: usize = 19;
+
+/// The largest number of bytes needed to write any `f64`, such as `-2.2250738585072014e-308`.
+///
+/// See [REQUIRED_SPACE_F32] for a description of the format.
+pub const REQUIRED_SPACE_F64: usize = 24;
+
+// Returns the power of ten of the first digit of the decimal.
+const fn scientific_exponent(decimal: Decimal) -> i32 {
+    decimal.exponent + decimal_length(decimal.mantissa) as i32 - 1
+}
+
+// Numbers outside of `1e-4..1e16` are written in exponential notation.
+const fn is_exponential(scientific: i32) -> bool {
+    scientific < -4 || scientific >= 16
+}
+
+// Counts the number of bytes needed to write the float.
+const fn float_len(negative: bool, float: Float) -> usize {
+    let sign = negative as usize;
+    match float {
+        Float::Nan => 3,
+        Float::Infinite | Float::Zero => sign + 3,
+        Float::Finite(decimal) => {
+            let digits = decimal_length(decimal.mantissa);
+            let scientific = scientific_exponent(decimal);
+            let body = if is_exponential(scientific) {
+                let fraction = if digits > 1 { digits } else { 0 };
+                let exponent = decimal_length(scientific.unsigned_abs() as u64);
+                1 + fraction + 1 + (scientific < 0) as usize + exponent
+            } else if decimal.exponent >= 0 {
+                digits + decimal.exponent as usize + 2
+            } else if scientific >= 0 {
+                digits + 1
+            } else {
+                digits + 1 + (-scientific) as usize
+            };
+            sign + body
+        }
+    }
+}
+
+// Copies `bytes` into `string` at `index`, returning the index following them.
+const fn copy_bytes(bytes: &[u8], string: &mut [MaybeUninit<u8>], mut index: usize) -> usize {
+    let mut position = 0;
+    while position < bytes.len() {
+        string[index] = MaybeUninit::new(bytes[position]);
+        index += 1;
+        position += 1;
+    }
+    index
+}
+
+// Writes `count` zero digits into `string` at `index`, returning the index following them.
+const fn fill_zeros(
+    count: usize,
+    alphabet: &Alphabet,
+    string: &mut [MaybeUninit<u8>],
+    mut index: usize,
+) -> usize {
+    let end = index + count;
+    while index < end {
+        string[index] = MaybeUninit::new(alphabet.digit(0));
+        index += 1;
+    }
+    index
+}
+
+// Writes the float into the end of `string` without validating the buffer length.
+const fn write_float<'a>(
+    negative: bool,
+    float: Float,
+    alphabet: &Alphabet,
+    string: &'a mut [MaybeUninit<u8>],
+) -> &'a [u8] {
+    let start = string.len() - float_len(negative, float);
+    let mut index = start;
+    if negative && !matches!(float, Float::Nan) {
+        string[index] = MaybeUninit::new(b'-');
+        index += 1;
+    }
+
+    match float {
+        Float::Nan => {
+            copy_bytes(b"NaN", string, index);
+        }
+        Float::Infinite => {
+            copy_bytes(b"inf", string, index);
+        }
+        Float::Zero => {
+            index = fill_zeros(1, alphabet, string, index);
+            string[index] = MaybeUninit::new(b'.');
+            fill_zeros(1, alphabet, string, index + 1);
+        }
+        Float::Finite(decimal) => {
+            let mut buffer = [MaybeUninit::uninit(); 20];
+            let digits = write_u64(decimal.mantissa, 10, alphabet, &mut buffer);
+            let scientific = scientific_exponent(decimal);
+
+            if is_exponential(scientific) {
+                let (first, fraction) = digits.split_at(1);
+                index = copy_bytes(first, string, index);
+                if !fraction.is_empty() {
+                    string[index] = MaybeUninit::new(b'.');
+                    index = copy_bytes(fraction, string, index + 1);
+                }
+                string[index] = MaybeUninit::new(b'e');
+                index += 1;
+                if scientific < 0 {
+                    string[index] = MaybeUninit::new(b'-');
+                    index += 1;
+                }
+                let mut buffer = [MaybeUninit::uninit(); 20];
+                let exponent =
+                    write_u64(scientific.unsigned_abs() as u64, 10, alphabet, &mut buffer);
+                copy_bytes(exponent, string, index);
+            } else if decimal.exponent >= 0 {
+                index = copy_bytes(digits, string, index);
+                index = fill_zeros(decimal.exponent as usize, alphabet, string, index);
+                string[index] = MaybeUninit::new(b'.');
+                fill_zeros(1, alphabet, string, index + 1);
+            } else if scientific >= 0 {
+                let (integer, fraction) = digits.split_at(scientific as usize + 1);
+                index = copy_bytes(integer, string, index);
+                string[index] = MaybeUninit::new(b'.');
+                copy_bytes(fraction, string, index + 1);
+            } else {
+                index = fill_zeros(1, alphabet, string, index);
+                string[index] = MaybeUninit::new(b'.');
+                index = fill_zeros((-scientific) as usize - 1, alphabet, string, index + 1);
+                copy_bytes(digits, string, index);
+            }
+        }
+    }
+
+    unsafe { assume_init(string.split_at(start).1) }
+}
+
+macro_rules! impl_float_numtoa_for {
+    (
+        $type_name:ty,
+        $decode_function_name:ident,
+        $required_space:ident,
+        $core_function_name:ident,
+        $str_function_name:ident,
+        $alphabet_function_name:ident,
+        $alphabet_str_function_name:ident,
+        $try_function_name:ident,
+        $try_str_function_name:ident,
+        $left_function_name:ident,
+        $uninit_function_name:ident,
+        $uninit_str_function_name:ident,
+        $digit_count_function_name:ident
+    ) => {
+        pub const fn $core_function_name(num: $type_name, string: &mut [u8]) -> &[u8] {
+            $alphabet_function_name(num, &Alphabet::UPPER, string)
+        }
+
+        pub const fn $str_function_name(num: $type_name, string: &mut [u8]) -> &str {
+            unsafe { core::str::from_utf8_unchecked($core_function_name(num, string)) }
+        }
+
+        pub const fn $alphabet_function_name<'a>(
+            num: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a [u8] {
+            assert!(alphabet.max_base() >= 10, "unsupported base");
+            debug_assert!(string.len() >= $required_space);
+
+            write_float(
+                num.is_sign_negative(),
+                $decode_function_name(num.to_bits()),
+                alphabet,
+                as_uninit_mut(string),
+            )
+        }
+
+        pub const fn $alphabet_str_function_name<'a>(
+            num: $type_name,
+            alphabet: &Alphabet,
+            string: &'a mut [u8],
+        ) -> &'a str {
+            unsafe {
+                core::str::from_utf8_unchecked($alphabet_function_name(num, alphabet, string))
+            }
+        }
+
+        pub const fn $try_function_name(
+            num: $type_name,
+            string: &mut [u8],
+        ) -> Result<&[u8], NumToAError> {
+            let negative = num.is_sign_negative();
+            let float = $decode_function_name(num.to_bits());
+
+            let needed = float_len(negative, float);
+            if string.len() < needed {
+                return Err(NumToAError::BufferTooSmall { needed });
+            }
+
+            Ok(write_float(
+                negative,
+                float,
+                &Alphabet::UPPER,
+                as_uninit_mut(string),
+            ))
+        }
+
+        pub const fn $try_str_function_name(
+            num: $type_name,
+            string: &mut [u8],
+        ) -> Result<&str, NumToAError> {
+            match $try_function_name(num, string) {
+                Ok(slice) => Ok(unsafe { core::str::from_utf8_unchecked(slice) }),
+                Err(why) => Err(why),
+            }
+        }
+
+        pub const fn $left_function_name(num: $type_name, string: &mut [u8]) -> usize {
+            let negative = num.is_sign_negative();
+            let float = $decode_function_name(num.to_bits());
+
+            let length = float_len(negative, float);
+            write_float(
+                negative,
+                float,
+                &Alphabet::UPPER,
+                as_uninit_mut(string.split_at_mut(length).0),
+            );
+            length
+        }
+
+        pub const fn $uninit_function_name(
+            num: $type_name,
+            string: &mut [MaybeUninit<u8>],
+        ) -> &[u8] {
+            debug_assert!(string.len() >= $required_space);
+
+            write_float(
+                num.is_sign_negative(),
+                $decode_function_name(num.to_bits()),
+                &Alphabet::UPPER,
+                string,
+            )
+        }
+
+        pub const fn $uninit_str_function_name(
+            num: $type_name,
+            string: &mut [MaybeUninit<u8>],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($uninit_function_name(num, string)) }
+        }
+
+        pub const fn $digit_count_function_name(num: $type_name) -> usize {
+            float_len(num.is_sign_negative(), $decode_function_name(num.to_bits()))
+        }
+    };
+}
+
+impl_float_numtoa_for!(
+    f32,
+    f2d,
+    REQUIRED_SPACE_F32,
+    numtoa_f32,
+    numtoa_f32_str,
+    numtoa_f32_with_alphabet,
+    numtoa_f32_with_alphabet_str,
+    try_numtoa_f32,
+    try_numtoa_f32_str,
+    numtoa_f32_left,
+    numtoa_f32_uninit,
+    numtoa_f32_uninit_str,
+    digit_count_f32
+);
+impl_float_numtoa_for!(
+    f64,
+    d2d,
+    REQUIRED_SPACE_F64,
+    numtoa_f64,
+    numtoa_f64_str,
+    numtoa_f64_with_alphabet,
+    numtoa_f64_with_alphabet_str,
+    try_numtoa_f64,
+    try_numtoa_f64_str,
+    numtoa_f64_left,
+    numtoa_f64_uninit,
+    numtoa_f64_uninit_str,
+    digit_count_f64
+);
+
+// The number of integer digits in `f32::MAX` and `f64::MAX`.
+const F32_INTEGER_DIGITS: usize = 39;
+const F64_INTEGER_DIGITS: usize = 309;
+
+// The number of digits in the largest exponent of an `f32` or `f64` written in exponential notation.
+const F32_EXPONENT_DIGITS: usize = 2;
+const F64_EXPONENT_DIGITS: usize = 3;
+
+/// The number of bytes needed to write any `f32` with [numtoa_f32_fixed], which writes the exact value of the float
+/// rounded to `precision` fractional digits, with exact ties rounded to even.
+pub const fn required_space_f32_fixed(precision: usize) -> usize {
+    1 + F32_INTEGER_DIGITS + (precision > 0) as usize + precision
+}
+
+/// The number of bytes needed to write any `f64` with [numtoa_f64_fixed], which writes the exact value of the float
+/// rounded to `precision` fractional digits, with exact ties rounded to even.
+pub const fn required_space_f64_fixed(precision: usize) -> usize {
+    1 + F64_INTEGER_DIGITS + (precision > 0) as usize + precision
+}
+
+/// The number of bytes needed to write any `f32` with [numtoa_f32_exp], which writes the exact value of the float
+/// in exponential notation rounded to `significant_digits` digits, with exact ties rounded to even.
+pub const fn required_space_f32_exp(significant_digits: usize) -> usize {
+    1 + significant_digits + (significant_digits > 1) as usize + 2 + F32_EXPONENT_DIGITS
+}
+
+/// The number of bytes needed to write any `f64` with [numtoa_f64_exp], which writes the exact value of the float
+/// in exponential notation rounded to `significant_digits` digits, with exact ties rounded to even.
+pub const fn required_space_f64_exp(significant_digits: usize) -> usize {
+    1 + significant_digits + (significant_digits > 1) as usize + 2 + F64_EXPONENT_DIGITS
+}
+
+// Decodes a finite `f64` into its exact value of `digits / 10^scale`.
+const fn exact_decimal(bits: u64) -> (Big, usize) {
+    let mantissa = bits & ((1u64 << 52) - 1);
+    let exponent = ((bits >> 52) & 0x7FF) as i32;
+    let (mantissa, exponent) = if exponent == 0 {
+        (mantissa, -1074)
+    } else {
+        (mantissa | (1u64 << 52), exponent - 1075)
+    };
+
+    let mut digits = Big::from_u64(mantissa);
+    if mantissa == 0 {
+        (digits, 0)
+    } else if exponent >= 0 {
+        digits.mul_pow2(exponent as u32);
+        (digits, 0)
+    } else {
+        // Multiplying by `5^k` turns the division by `2^k` into a division by `10^k`.
+        digits.mul_pow5(-exponent as u32);
+        (digits, -exponent as usize)
+    }
+}
+
+// Writes `bytes` so that they end before `end`, returning the index of the first byte.
+const fn copy_bytes_before(bytes: &[u8], string: &mut [u8], mut end: usize) -> usize {
+    let mut position = bytes.len();
+    while position > 0 {
+        position -= 1;
+        end -= 1;
+        string[end] = bytes[position];
+    }
+    end
+}
+
+// Writes `count` zeros so that they end before `end`, returning the index of the first zero.
+const fn fill_zeros_before(count: usize, string: &mut [u8], mut end: usize) -> usize {
+    let start = end - count;
+    while end > start {
+        end -= 1;
+        string[end] = b'0';
+    }
+    start
+}
+
+// Writes the decimal digits of `value` so that they end before `end`, padded with leading zeros to `min_digits`,
+// and with a point before the last `fraction` digits unless `fraction` is zero. Returns the index of the first byte.
+const fn write_big_before(
+    mut value: Big,
+    min_digits: usize,
+    fraction: usize,
+    string: &mut [u8],
+    mut end: usize,
+) -> usize {
+    let mut written = 0;
+    let mut chunk = 0;
+    let mut chunk_digits = 0;
+    loop {
+        if chunk_digits == 0 && !value.is_zero() {
+            chunk = value.div_rem_small(POW10_9);
+            chunk_digits = 9;
+        }
+        if chunk == 0 && value.is_zero() && written >= min_digits {
+            return end;
+        }
+        if fraction != 0 && written == fraction {
+            end -= 1;
+            string[end] = b'.';
+        }
+        end -= 1;
+        string[end] = b'0' + (chunk % 10) as u8;
+        chunk /= 10;
+        chunk_digits = if chunk_digits > 0 {
+            chunk_digits - 1
+        } else {
+            0
+        };
+        written += 1;
+    }
+}
+
+// Writes the sign of a float before `end`, returning the index of the first byte.
+const fn write_sign_before(negative: bool, string: &mut [u8], end: usize) -> usize {
+    if negative {
+        string[end - 1] = b'-';
+        end - 1
+    } else {
+        end
+    }
+}
+
+// Writes the float rounded to `precision` fractional digits into the end of `string`.
+const fn write_fixed(num: f64, precision: usize, string: &mut [u8]) -> &[u8] {
+    let mut end = string.len();
+    if num.is_nan() {
+        end = copy_bytes_before(b"NaN", string, end);
+    } else if num.is_infinite() {
+        end = copy_bytes_before(b"inf", string, end);
+        end = write_sign_before(num.is_sign_negative(), string, end);
+    } else {
+        let (mut digits, scale) = exact_decimal(num.to_bits());
+        if precision >= scale {
+            end = fill_zeros_before(precision - scale, string, end);
+            if scale == 0 && precision > 0 {
+                end -= 1;
+                string[end] = b'.';
+            }
+            end = write_big_before(digits, scale + 1, scale, string, end);
+        } else {
+            digits.div_pow10_round(scale - precision);
+            end = write_big_before(digits, precision + 1, precision, string, end);
+        }
+        end = write_sign_before(num.is_sign_negative(), string, end);
+    }
+
+    string.split_at(end).1
+}
+
+// Writes the float in exponential notation rounded to `significant_digits` digits into the end of `string`.
+const fn write_exp(num: f64, significant_digits: usize, string: &mut [u8]) -> &[u8] {
+    assert!(
+        significant_digits > 0,
+        "at least one significant digit is required"
+    );
+
+    let mut end = string.len();
+    if num.is_nan() {
+        end = copy_bytes_before(b"NaN", string, end);
+    } else if num.is_infinite() {
+        end = copy_bytes_before(b"inf", string, end);
+        end = write_sign_before(num.is_sign_negative(), string, end);
+    } else {
+        let (mut digits, scale) = exact_decimal(num.to_bits());
+        let count = digits.digits();
+        let mut exponent = count as isize - 1 - scale as isize;
+        let mut padding = 0;
+        if count > significant_digits {
+            digits.div_pow10_round(count - significant_digits);
+            if digits.digits() > significant_digits {
+                // Rounding carried into a new digit, leaving a power of ten.
+                digits.div_pow10_round(1);
+                exponent += 1;
+            }
+        } else {
+            padding = significant_digits - count;
+        }
+
+        let mut buffer = [MaybeUninit::uninit(); 20];
+        let exponent_digits = write_u64(
+            exponent.unsigned_abs() as u64,
+            10,
+            &Alphabet::UPPER,
+            &mut buffer,
+        );
+        end = copy_bytes_before(exponent_digits, string, end);
+        end = write_sign_before(exponent < 0, string, end);
+        end -= 1;
+        string[end] = b'e';
+
+        end = fill_zeros_before(padding, string, end);
+        let count = significant_digits - padding;
+        if count == 1 && padding > 0 {
+            end -= 1;
+            string[end] = b'.';
+        }
+        end = write_big_before(digits, count, count - 1, string, end);
+        end = write_sign_before(num.is_sign_negative(), string, end);
+    }
+
+    string.split_at(end).1
+}
+
+macro_rules! impl_float_exact_numtoa_for {
+    (
+        $type_name:ty,
+        $fixed_function_name:ident,
+        $fixed_str_function_name:ident,
+        $exp_function_name:ident,
+        $exp_str_function_name:ident,
+        $required_fixed_function_name:ident,
+        $required_exp_function_name:ident
+    ) => {
+        pub const fn $fixed_function_name(
+            num: $type_name,
+            precision: usize,
+            string: &mut [u8],
+        ) -> &[u8] {
+            debug_assert!(string.len() >= $required_fixed_function_name(precision));
+            write_fixed(num as f64, precision, string)
+        }
+
+        pub const fn $fixed_str_function_name(
+            num: $type_name,
+            precision: usize,
+            string: &mut [u8],
+        ) -> &str {
+            unsafe { core::str::from_utf8_unchecked($fixed_function_name(num, precision, string)) }
+        }
+
+        pub const fn $exp_function_name(
+            num: $type_name,
+            significant_digits: usize,
+            string: &mut [u8],
+        ) -> &[u8] {
+            debug_assert!(string.len() >= $required_exp_function_name(significant_digits));
+            write_exp(num as f64, significant_digits, string)
+        }
+
+        pub const fn $exp_str_function_name(
+            num: $type_name,
+            significant_digits: usize,
+            string: &mut [u8],
+        ) -> &str {
+            unsafe {
+                core::str::from_utf8_unchecked($exp_function_name(num, significant_digits, string))
+            }
+        }
+    };
+}
+
+impl_float_exact_numtoa_for!(
+    f32,
+    numtoa_f32_fixed,
+    numtoa_f32_fixed_str,
+    numtoa_f32_exp,
+    numtoa_f32_exp_str,
+    required_space_f32_fixed,
+    required_space_f32_exp
+);
+impl_float_exact_numtoa_for!(
+    f64,
+    numtoa_f64_fixed,
+    numtoa_f64_fixed_str,
+    numtoa_f64_exp,
+    numtoa_f64_exp_str,
+    required_space_f64_fixed,
+    required_space_f64_exp
+);
+
+#[cfg(test)]
+mod float_test {
+    extern crate std;
+
+    use self::std::format;
+    use super::*;
+
+    // A xorshift generator, so that the same bit patterns are tested on each run.
+    fn random_bits(count: usize) -> impl Iterator<Item = u64> {
+        let mut state = 0x853C_49E6_748F_EA9Bu64;
+        (0..count).map(move |_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+    }
+
+    #[test]
+    fn special_values() {
+        let mut buffer = [0u8; REQUIRED_SPACE_F64];
+        assert_eq!(numtoa_f64_str(0.0, &mut buffer), "0.0");
+        assert_eq!(numtoa_f64_str(-0.0, &mut buffer), "-0.0");
+        assert_eq!(numtoa_f64_str(f64::NAN, &mut buffer), "NaN");
+        assert_eq!(numtoa_f64_str(-f64::NAN, &mut buffer), "NaN");
+        assert_eq!(numtoa_f64_str(f64::INFINITY, &mut buffer), "inf");
+        assert_eq!(numtoa_f64_str(f64::NEG_INFINITY, &mut buffer), "-inf");
+        assert_eq!(numtoa_f32_str(-0.0, &mut buffer), "-0.0");
+        assert_eq!(numtoa_f32_str(f32::NAN, &mut buffer), "NaN");
+        assert_eq!(numtoa_f32_str(f32::NEG_INFINITY, &mut buffer), "-inf");
+    }
+
+    #[test]
+    fn notation() {
+        let mut buffer = [0u8; REQUIRED_SPACE_F64];
+        assert_eq!(numtoa_f64_str(1.0, &mut buffer), "1.0");
+        assert_eq!(numtoa_f64_str(0.1, &mut buffer), "0.1");
+        assert_eq!(numtoa_f64_str(-123.456, &mut buffer), "-123.456");
+        assert_eq!(numtoa_f64_str(0.0001, &mut buffer), "0.0001");
+        assert_eq!(numtoa_f64_str(0.00001, &mut buffer), "1e-5");
+        assert_eq!(numtoa_f64_str(1e15, &mut buffer), "1000000000000000.0");
+        assert_eq!(numtoa_f64_str(1e16, &mut buffer), "1e16");
+        assert_eq!(numtoa_f64_str(-1.5e-7, &mut buffer), "-1.5e-7");
+        assert_eq!(numtoa_f64_str(1.2345e300, &mut buffer), "1.2345e300");
+        assert_eq!(numtoa_f32_str(0.3, &mut buffer), "0.3");
+        assert_eq!(numtoa_f32_str(16777216.0, &mut buffer), "16777216.0");
+        assert_eq!(numtoa_f32_str(1e20, &mut buffer), "1e20");
+    }
+
+    #[test]
+    fn extremes() {
+        let mut buffer = [0u8; REQUIRED_SPACE_F64];
+        assert_eq!(
+            numtoa_f64_str(f64::MAX, &mut buffer),
+            "1.7976931348623157e308"
+        );
+        assert_eq!(
+            numtoa_f64_str(-f64::MIN_POSITIVE, &mut buffer),
+            "-2.2250738585072014e-308"
+        );
+        assert_eq!(numtoa_f64_str(f64::from_bits(1), &mut buffer), "5e-324");
+        assert_eq!(numtoa_f32_str(f32::MAX, &mut buffer), "3.4028235e38");
+        assert_eq!(
+            numtoa_f32_str(-f32::MIN_POSITIVE, &mut buffer),
+            "-1.1754944e-38"
+        );
+        assert_eq!(numtoa_f32_str(f32::from_bits(1), &mut buffer), "1e-45");
+
+        // The longest decimal form, with the largest number of digits before the point.
+        let mut buffer = [0u8; REQUIRED_SPACE_F32];
+        assert_eq!(
+            numtoa_f32_str(-1.2345678e15, &mut buffer),
+            "-1234567800000000.0"
+        );
+    }
+
+    #[test]
+    fn matches_debug_f64() {
+        let mut buffer = [0u8; REQUIRED_SPACE_F64];
+        for bits in random_bits(100_000) {
+            let num = f64::from_bits(bits);
+            assert_eq!(numtoa_f64_str(num, &mut buffer), format!("{:?}", num));
+        }
+        for exponent in -325..=308 {
+            let num: f64 = format!("1e{}", exponent).parse().unwrap();
+            assert_eq!(numtoa_f64_str(num, &mut buffer), format!("{:?}", num));
+        }
+        for num in 0..10_000 {
+            let num = num as f64 / 16.0;
+            assert_eq!(numtoa_f64_str(num, &mut buffer), format!("{:?}", num));
+        }
+    }
+
+    #[test]
+    fn matches_debug_f32() {
+        let mut buffer = [0u8; REQUIRED_SPACE_F32];
+        for bits in random_bits(100_000) {
+            let num = f32::from_bits(bits as u32);
+            assert_eq!(numtoa_f32_str(num, &mut buffer), format!("{:?}", num));
+        }
+        // Subnormals, and the transition to normal numbers.
+        for bits in (0..0x0100_0000u32).step_by(97) {
+            let num = f32::from_bits(bits);
+            assert_eq!(numtoa_f32_str(num, &mut buffer), format!("{:?}", num));
+        }
+    }
+
+    #[test]
+    fn ties_round_up() {
+        // The value is exactly 1144061379383392.25, where both neighbours round-trip.
+        let num = f64::from_bits(0x4310_4212_307A_4181);
+        assert_eq!(
+            numtoa_f64_str(num, &mut [0u8; REQUIRED_SPACE_F64]),
+            "1144061379383392.3"
+        );
+    }
+
+    #[test]
+    fn try_exact_buffer() {
+        assert_eq!(try_numtoa_f64_str(0.5, &mut [0u8; 3]), Ok("0.5"));
+        assert_eq!(
+            try_numtoa_f64_str(0.5, &mut [0u8; 2]),
+            Err(NumToAError::BufferTooSmall { needed: 3 })
+        );
+        assert_eq!(try_numtoa_f32_str(f32::NAN, &mut [0u8; 3]), Ok("NaN"));
+        assert_eq!(
+            try_numtoa_f32_str(-1e-7, &mut [0u8; 4]),
+            Err(NumToAError::BufferTooSmall { needed: 5 })
+        );
+        for bits in random_bits(1000) {
+            let num = f64::from_bits(bits);
+            let expected = format!("{:?}", num);
+            let mut buffer = [0u8; REQUIRED_SPACE_F64];
+            let exact = &mut buffer[..expected.len()];
+            assert_eq!(try_numtoa_f64_str(num, exact), Ok(expected.as_str()));
+        }
+    }
+
+    #[test]
+    fn left_aligned() {
+        let mut buffer = [b'.'; REQUIRED_SPACE_F64];
+        let length = numtoa_f64_left(-1.5e-7, &mut buffer);
+        assert_eq!(&buffer[..length], b"-1.5e-7");
+        assert_eq!(buffer[length], b'.');
+        assert_eq!(numtoa_f32_left(f32::NAN, &mut [0u8; 3]), 3);
+        for bits in random_bits(1000) {
+            let num = f64::from_bits(bits);
+            let length = numtoa_f64_left(num, &mut buffer);
+            assert_eq!(&buffer[..length], format!("{:?}", num).as_bytes());
+        }
+    }
+
+    #[test]
+    fn uninit() {
+        let mut buffer = [MaybeUninit::uninit(); REQUIRED_SPACE_F64];
+        assert_eq!(
+            numtoa_f64_uninit_str(-f64::MAX, &mut buffer),
+            "-1.7976931348623157e308"
+        );
+        assert_eq!(
+            numtoa_f32_uninit(0.1, &mut buffer[..REQUIRED_SPACE_F32]),
+            b"0.1"
+        );
+    }
+
+    #[test]
+    fn alphabet() {
+        let mut buffer = [0u8; REQUIRED_SPACE_F64];
+        let alphabet = Alphabet::from_digits(b"abcdefghij");
+        assert_eq!(
+            numtoa_f64_with_alphabet_str(-0.0, &alphabet, &mut buffer),
+            "-a.a"
+        );
+        assert_eq!(
+            numtoa_f64_with_alphabet_str(1.25e-9, &alphabet, &mut buffer),
+            "b.cfe-j"
+        );
+        assert_eq!(
+            numtoa_f32_with_alphabet_str(f32::INFINITY, &alphabet, &mut buffer),
+            "inf"
+        );
+    }
+
+    #[test]
+    fn fixed() {
+        let mut buffer = [0u8; 400];
+        assert_eq!(numtoa_f64_fixed_str(1.0, 3, &mut buffer), "1.000");
+        assert_eq!(numtoa_f64_fixed_str(-2.5, 0, &mut buffer), "-2");
+        assert_eq!(numtoa_f64_fixed_str(3.5, 0, &mut buffer), "4");
+        assert_eq!(numtoa_f64_fixed_str(0.125, 2, &mut buffer), "0.12");
+        assert_eq!(numtoa_f64_fixed_str(0.375, 2, &mut buffer), "0.38");
+        assert_eq!(numtoa_f64_fixed_str(-0.01, 1, &mut buffer), "-0.0");
+        assert_eq!(numtoa_f64_fixed_str(9.9996, 3, &mut buffer), "10.000");
+        assert_eq!(
+            numtoa_f64_fixed_str(1e21, 1, &mut buffer),
+            "1000000000000000000000.0"
+        );
+        // The nearest double to 0.1 is slightly above it.
+        assert_eq!(
+            numtoa_f64_fixed_str(0.1, 20, &mut buffer),
+            "0.10000000000000000555"
+        );
+        assert_eq!(numtoa_f64_fixed_str(f64::NAN, 2, &mut buffer), "NaN");
+        assert_eq!(
+            numtoa_f64_fixed_str(f64::NEG_INFINITY, 2, &mut buffer),
+            "-inf"
+        );
+        assert_eq!(numtoa_f32_fixed_str(0.1, 10, &mut buffer), "0.1000000015");
+        assert_eq!(numtoa_f32_fixed_str(-0.0, 2, &mut buffer), "-0.00");
+    }
+
+    #[test]
+    fn exp() {
+        let mut buffer = [0u8; 64];
+        assert_eq!(numtoa_f64_exp_str(1.23456e-7, 4, &mut buffer), "1.235e-7");
+        assert_eq!(numtoa_f64_exp_str(1.125, 3, &mut buffer), "1.12e0");
+        assert_eq!(numtoa_f64_exp_str(1.375, 3, &mut buffer), "1.38e0");
+        assert_eq!(numtoa_f64_exp_str(-25.0, 1, &mut buffer), "-2e1");
+        assert_eq!(numtoa_f64_exp_str(35.0, 1, &mut buffer), "4e1");
+        assert_eq!(numtoa_f64_exp_str(9.99, 2, &mut buffer), "1.0e1");
+        assert_eq!(numtoa_f64_exp_str(0.0, 4, &mut buffer), "0.000e0");
+        assert_eq!(numtoa_f64_exp_str(1.0, 3, &mut buffer), "1.00e0");
+        assert_eq!(numtoa_f64_exp_str(f64::MAX, 4, &mut buffer), "1.798e308");
+        assert_eq!(
+            numtoa_f64_exp_str(-f64::from_bits(1), 5, &mut buffer),
+            "-4.9407e-324"
+        );
+        assert_eq!(numtoa_f64_exp_str(f64::INFINITY, 4, &mut buffer), "inf");
+        assert_eq!(
+            numtoa_f32_exp_str(f32::MAX, 9, &mut buffer),
+            "3.40282347e38"
+        );
+        assert_eq!(
+            numtoa_f32_exp_str(f32::from_bits(1), 2, &mut buffer),
+            "1.4e-45"
+        );
+    }
+
+    #[test]
+    fn exact_matches_format() {
+        let mut buffer = [0u8; 1400];
+        for (index, bits) in random_bits(2000).enumerate() {
+            let num = f64::from_bits(bits);
+            let digits = index % 30;
+            let fixed = &mut buffer[..required_space_f64_fixed(digits)];
+            assert_eq!(
+                numtoa_f64_fixed_str(num, digits, fixed),
+                format!("{:.*}", digits, num)
+            );
+            let exp = &mut buffer[..required_space_f64_exp(digits + 1)];
+            assert_eq!(
+                numtoa_f64_exp_str(num, digits + 1, exp),
+                format!("{:.*e}", digits, num)
+            );
+
+            let num = f32::from_bits(bits as u32);
+            let fixed = &mut buffer[..required_space_f32_fixed(digits)];
+            assert_eq!(
+                numtoa_f32_fixed_str(num, digits, fixed),
+                format!("{:.*}", digits, num)
+            );
+            let exp = &mut buffer[..required_space_f32_exp(digits + 1)];
+            assert_eq!(
+                numtoa_f32_exp_str(num, digits + 1, exp),
+                format!("{:.*e}", digits, num)
+            );
+        }
+
+        // The smallest subnormal has 1074 fractional digits.
+        let num = f64::from_bits(1);
+        let fixed = &mut buffer[..required_space_f64_fixed(1080)];
+        assert_eq!(
+            numtoa_f64_fixed_str(num, 1080, fixed),
+            format!("{:.1080}", num)
+        );
+    }
+
+    #[test]
+    fn exact_required_space() {
+        let mut buffer = [0u8; 400];
+        let fixed = &mut buffer[..required_space_f64_fixed(2)];
+        assert_eq!(numtoa_f64_fixed(-f64::MAX, 2, fixed).len(), fixed.len());
+        let fixed = &mut buffer[..required_space_f32_fixed(0)];
+        assert_eq!(numtoa_f32_fixed(-f32::MAX, 0, fixed).len(), fixed.len());
+        let exp = &mut buffer[..required_space_f64_exp(3)];
+        assert_eq!(numtoa_f64_exp(-f64::MIN_POSITIVE, 3, exp).len(), exp.len());
+        let exp = &mut buffer[..required_space_f32_exp(1)];
+        assert_eq!(numtoa_f32_exp(-f32::MIN_POSITIVE, 1, exp).len(), exp.len());
+    }
+
+    #[test]
+    fn exact_const_context() {
+        const SPACE: usize = required_space_f64_exp(4);
+        const BUFFER: [u8; SPACE] = {
+            let mut buffer = [b' '; SPACE];
+            let _ = numtoa_f64_exp(core::f64::consts::E, 4, &mut buffer);
+            buffer
+        };
+        assert_eq!(&BUFFER[SPACE - 7..], b"2.718e0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn exp_without_digits() {
+        let _ = numtoa_f64_exp(1.0, 0, &mut [0u8; 32]);
+    }
+
+    #[test]
+    fn const_context() {
+        const BUFFER: [u8; REQUIRED_SPACE_F64] = {
+            let mut buffer = [b' '; REQUIRED_SPACE_F64];
+            let _ = numtoa_f64(core::f64::consts::PI, &mut buffer);
+            buffer
+        };
+        assert_eq!(&BUFFER[REQUIRED_SPACE_F64 - 17..], b"3.141592653589793");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn buffer_too_small() {
+        let _ = numtoa_f64(1.0, &mut [0u8; REQUIRED_SPACE_F64 - 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn alphabet_too_small() {
+        let _ = numtoa_f32_with_alphabet(1.0, &Alphabet::from_digits(b"01"), &mut [0u8; 32]);
+    }
+}
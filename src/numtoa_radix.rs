@@ -0,0 +1,140 @@
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+
+use crate::numtoa_error::NumToAError;
+
+/// A numeric base which is guaranteed to be within the supported range of 2 to 36.
+///
+/// Validity is checked once on construction, so conversions that accept a [Radix] never have to
+/// check the base again.
+///
+/// # Example
+/// ```
+/// use numtoa::{NumToA, Radix};
+///
+/// const BASE_36: Radix = Radix::new(36);
+///
+/// let mut buffer = [0u8; 20];
+/// assert_eq!(255u8.numtoa_radix(Radix::HEX, &mut buffer), b"FF");
+/// assert_eq!(1295u16.numtoa_radix(BASE_36, &mut buffer), b"ZZ");
+/// assert!(Radix::try_new(37).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Radix(u8);
+
+impl Radix {
+    /// The smallest supported base.
+    pub const MIN: Radix = Radix(2);
+    /// The largest supported base.
+    pub const MAX: Radix = Radix(36);
+
+    /// Base 2
+    pub const BIN: Radix = Radix(2);
+    /// Base 8
+    pub const OCT: Radix = Radix(8);
+    /// Base 10
+    pub const DEC: Radix = Radix(10);
+    /// Base 16
+    pub const HEX: Radix = Radix(16);
+
+    /// Creates a radix from the given base.
+    ///
+    /// # Panics
+    /// If the base is not within 2 to 36. In const contexts, this is a compile-time error.
+    pub const fn new(base: u32) -> Radix {
+        match Radix::try_new(base) {
+            Ok(radix) => radix,
+            Err(_) => panic!("unsupported base"),
+        }
+    }
+
+    /// Creates a radix from the given base, or returns [NumToAError::UnsupportedBase] if it is not
+    /// within 2 to 36.
+    pub const fn try_new(base: u32) -> Result<Radix, NumToAError> {
+        if base >= Radix::MIN.0 as u32 && base <= Radix::MAX.0 as u32 {
+            Ok(Radix(base as u8))
+        } else {
+            Err(NumToAError::UnsupportedBase)
+        }
+    }
+
+    // Creates a radix from a base which the caller has already validated.
+    pub(crate) const fn new_unchecked(base: u8) -> Radix {
+        debug_assert!(base >= Radix::MIN.0 && base <= Radix::MAX.0);
+        Radix(base)
+    }
+
+    /// Returns the base as an integer.
+    pub const fn get(self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl Display for Radix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Radix> for u32 {
+    fn from(radix: Radix) -> u32 {
+        radix.get()
+    }
+}
+
+impl TryFrom<u32> for Radix {
+    type Error = NumToAError;
+
+    fn try_from(base: u32) -> Result<Radix, NumToAError> {
+        Radix::try_new(base)
+    }
+}
+
+#[cfg(test)]
+mod radix_test {
+    use super::*;
+
+    #[test]
+    fn supported_range() {
+        assert_eq!(Err(NumToAError::UnsupportedBase), Radix::try_new(0));
+        assert_eq!(Err(NumToAError::UnsupportedBase), Radix::try_new(1));
+        assert_eq!(Err(NumToAError::UnsupportedBase), Radix::try_new(37));
+        assert_eq!(Err(NumToAError::UnsupportedBase), Radix::try_new(u32::MAX));
+        for base in 2..=36 {
+            assert_eq!(base, Radix::new(base).get());
+        }
+    }
+
+    #[test]
+    fn predefined() {
+        assert_eq!(Radix::new(2), Radix::BIN);
+        assert_eq!(Radix::new(8), Radix::OCT);
+        assert_eq!(Radix::new(10), Radix::DEC);
+        assert_eq!(Radix::new(16), Radix::HEX);
+    }
+
+    #[test]
+    fn const_construction() {
+        const BASE_36: Radix = Radix::new(36);
+        assert_eq!(36, BASE_36.get());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Ok(Radix::HEX), Radix::try_from(16));
+        assert_eq!(Err(NumToAError::UnsupportedBase), Radix::try_from(99));
+        assert_eq!(10, u32::from(Radix::DEC));
+    }
+
+    #[test]
+    #[should_panic]
+    fn base_too_low() {
+        let _ = Radix::new(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn base_too_high() {
+        let _ = Radix::new(37);
+    }
+}
@@ -1,15 +1,26 @@
+use core::mem::MaybeUninit;
+
+use crate::numtoa_alphabet::Alphabet;
+use crate::numtoa_const::{AsciiConversion, BaseN};
 use crate::numtoa_core::*;
+use crate::numtoa_error::NumToAError;
+use crate::numtoa_float::*;
+use crate::numtoa_radix::Radix;
 
 /// Converts a number into a string representation, storing the conversion into a mutable byte slice.
+///
+/// Floats are always written in base 10 in their shortest round-trip form, as described in [REQUIRED_SPACE_F32].
+/// Any other base is unsupported: the methods of floats panic when given one, except for [NumToA::try_numtoa], which
+/// returns [NumToAError::UnsupportedBase], and [NumToA::numtoa_array], which fails to compile.
 pub trait NumToA {
     /// Given a base for encoding and a mutable byte slice, write the number into the byte slice and return the
     /// indice where the inner string begins. The inner string can be extracted by slicing the byte slice from
     /// that indice.
     ///
     /// # Panics
-    /// If the supplied buffer is smaller than the number of bytes needed to write the integer, this will panic.
-    /// On debug builds, this function will perform a check on base 10 conversions to ensure that the input array
-    /// is large enough to hold the largest possible value in digits.
+    /// If the base is not within 2 to 36, or is not 10 for a float, or if the supplied buffer is smaller than the
+    /// number of bytes needed to write the number, this will panic. On debug builds, this function will perform a
+    /// check to ensure that the input array is large enough to hold the largest possible value in digits.
     ///
     /// # Example
     /// ```
@@ -33,14 +44,261 @@ pub trait NumToA {
     fn numtoa(self, base: Self, string: &mut [u8]) -> &[u8];
 
     /// Convenience method for quickly getting a string from the input's array buffer.
+    ///
+    /// # Panics
+    /// As with [NumToA::numtoa].
     fn numtoa_str(self, base: Self, buf: &mut [u8]) -> &str;
+
+    /// Equivalent to [NumToA::numtoa], but with a base that has already been validated by [Radix].
+    ///
+    /// # Panics
+    /// If the number is a float and the radix is not [Radix::DEC], or if the supplied buffer is too small.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{NumToA, Radix};
+    ///
+    /// let mut buffer = [0u8; 32];
+    /// assert_eq!(0xBEEFu16.numtoa_radix(Radix::HEX, &mut buffer), b"BEEF");
+    /// assert_eq!((-5i8).numtoa_radix(Radix::BIN, &mut buffer), b"-101");
+    /// ```
+    fn numtoa_radix(self, radix: Radix, string: &mut [u8]) -> &[u8];
+
+    /// Convenience method for quickly getting a string from the input's array buffer.
+    ///
+    /// # Panics
+    /// As with [NumToA::numtoa_radix].
+    fn numtoa_radix_str(self, radix: Radix, buf: &mut [u8]) -> &str;
+
+    /// Equivalent to [NumToA::numtoa_radix], but with the digits taken from the given [Alphabet].
+    ///
+    /// # Panics
+    /// If the radix is larger than [Alphabet::max_base], or the number is a float and the radix is not [Radix::DEC],
+    /// or if the supplied buffer is too small.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{Alphabet, NumToA, Radix};
+    ///
+    /// let mut buffer = [0u8; 32];
+    /// assert_eq!(0xBEEFu16.numtoa_with_alphabet(Radix::HEX, &Alphabet::LOWER, &mut buffer), b"beef");
+    /// ```
+    fn numtoa_with_alphabet<'a>(
+        self,
+        radix: Radix,
+        alphabet: &Alphabet,
+        string: &'a mut [u8],
+    ) -> &'a [u8];
+
+    /// Convenience method for quickly getting a string from the input's array buffer.
+    ///
+    /// # Panics
+    /// As with [NumToA::numtoa_with_alphabet].
+    fn numtoa_with_alphabet_str<'a>(
+        self,
+        radix: Radix,
+        alphabet: &Alphabet,
+        buf: &'a mut [u8],
+    ) -> &'a str;
+
+    /// Fallible variant of [NumToA::numtoa] which never panics.
+    ///
+    /// Returns [NumToAError::UnsupportedBase] if the base is not within 2 to 36, or
+    /// [NumToAError::BufferTooSmall] if the number does not fit into the supplied buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{NumToA, NumToAError};
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// assert_eq!(1234.try_numtoa(10, &mut buffer), Ok(&b"1234"[..]));
+    /// assert_eq!(12345.try_numtoa(10, &mut buffer), Err(NumToAError::BufferTooSmall { needed: 5 }));
+    /// assert_eq!(12345.try_numtoa(37, &mut buffer), Err(NumToAError::UnsupportedBase));
+    /// ```
+    fn try_numtoa(self, base: Self, string: &mut [u8]) -> Result<&[u8], NumToAError>;
+
+    /// Fallible variant of [NumToA::numtoa_str] which never panics.
+    fn try_numtoa_str(self, base: Self, buf: &mut [u8]) -> Result<&str, NumToAError>;
+
+    /// Writes the number into the start of the byte slice rather than the end, and returns the number of bytes
+    /// written. This allows numbers to be appended to a larger buffer in place.
+    ///
+    /// # Panics
+    /// If the base is not within 2 to 36, or is not 10 for a float, or if the supplied buffer is smaller than the
+    /// number of bytes needed to write this number.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::NumToA;
+    ///
+    /// let mut line = [0u8; 32];
+    /// let mut length = 0;
+    /// for number in [12, -345, 6789] {
+    ///     length += number.write_left(10, &mut line[length..]);
+    ///     line[length] = b' ';
+    ///     length += 1;
+    /// }
+    /// assert_eq!(&line[..length], b"12 -345 6789 ");
+    /// ```
+    fn write_left(self, base: Self, string: &mut [u8]) -> usize;
+
+    /// Equivalent to [NumToA::numtoa], but writes into a buffer which may be uninitialized, returning the region
+    /// which has been initialized.
+    ///
+    /// # Panics
+    /// As with [NumToA::numtoa].
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::NumToA;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buffer = [MaybeUninit::uninit(); 20];
+    /// assert_eq!(12345u32.numtoa_uninit(10, &mut buffer), b"12345");
+    /// assert_eq!((-42i8).numtoa_uninit_str(10, &mut buffer), "-42");
+    /// ```
+    fn numtoa_uninit(self, base: Self, string: &mut [MaybeUninit<u8>]) -> &[u8];
+
+    /// Convenience method for quickly getting a string from the input's array buffer.
+    ///
+    /// # Panics
+    /// As with [NumToA::numtoa].
+    fn numtoa_uninit_str(self, base: Self, buf: &mut [MaybeUninit<u8>]) -> &str;
+
+    /// Equivalent to [NumToA::numtoa], but writes into the byte slice without bounds checks.
+    ///
+    /// # Safety
+    /// The byte slice must be large enough to hold any number of this type in the given base, as given by the
+    /// `REQUIRED_SPACE` constants of [BaseN](crate::BaseN). On debug builds, this is checked. Floats are always
+    /// written with bounds checks.
+    ///
+    /// # Panics
+    /// If the base is not within 2 to 36, or is not 10 for a float. Floats also panic if the buffer is too small.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{BaseN, NumToA};
+    ///
+    /// let mut buffer = [0u8; BaseN::<10>::REQUIRED_SPACE_I32];
+    /// assert_eq!(unsafe { (-6235i32).numtoa_unchecked(10, &mut buffer) }, b"-6235");
+    /// ```
+    unsafe fn numtoa_unchecked(self, base: Self, string: &mut [u8]) -> &[u8];
+
+    /// Convenience method for quickly getting a string from the input's array buffer.
+    ///
+    /// # Safety
+    /// As with [NumToA::numtoa_unchecked].
+    ///
+    /// # Panics
+    /// As with [NumToA::numtoa_unchecked].
+    unsafe fn numtoa_unchecked_str(self, base: Self, buf: &mut [u8]) -> &str;
+
+    /// The number of bytes needed to write this number in the given base, including the sign of negative numbers.
+    /// For floats, this is the length of their shortest representation, and the base must be 10.
+    ///
+    /// # Panics
+    /// If the number is a float and the base is not 10.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{NumToA, Radix};
+    ///
+    /// assert_eq!(12345u32.digit_count(Radix::DEC), 5);
+    /// assert_eq!((-256i16).digit_count(Radix::HEX), 4);
+    /// assert_eq!(0.1f64.digit_count(Radix::DEC), 3);
+    /// ```
+    fn digit_count(self, radix: Radix) -> usize;
+
+    /// Equivalent to [NumToA::numtoa], but with the base given at compile time, and an array which is checked at
+    /// compile time to be large enough to hold any number of this type in that base.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::NumToA;
+    ///
+    /// let mut buffer = [0u8; 20];
+    /// assert_eq!(u64::MAX.numtoa_array::<10, 20>(&mut buffer), b"18446744073709551615");
+    /// assert_eq!(255u8.numtoa_array::<2, 20>(&mut buffer), b"11111111");
+    /// ```
+    ///
+    /// An array which is too small for the type fails to compile, as does a float in any base but 10.
+    ///
+    /// ```compile_fail
+    /// use numtoa::NumToA;
+    ///
+    /// let mut buffer = [0u8; 19];
+    /// let _ = 0u64.numtoa_array::<10, 19>(&mut buffer);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use numtoa::{NumToA, REQUIRED_SPACE_F64};
+    ///
+    /// let mut buffer = [0u8; REQUIRED_SPACE_F64];
+    /// let _ = 0.5f64.numtoa_array::<16, REQUIRED_SPACE_F64>(&mut buffer);
+    /// ```
+    fn numtoa_array<const BASE: u32, const N: usize>(self, string: &mut [u8; N]) -> &[u8]
+    where
+        Self: Sized;
+
+    /// Convenience method for quickly getting a string from the input's array buffer.
+    fn numtoa_array_str<const BASE: u32, const N: usize>(self, buf: &mut [u8; N]) -> &str
+    where
+        Self: Sized;
+
+    /// Converts the number into an [AsciiNumber](crate::AsciiNumber) in a base given at compile time, which is large
+    /// enough to hold any number of this type in that base. This is equivalent to the functions of [BaseN], but is
+    /// also available to generic code through the [AsciiConversion] bound.
+    ///
+    /// # Example
+    /// ```
+    /// use numtoa::{AsciiConversion, BaseN, NumToA};
+    ///
+    /// fn hex<T: NumToA>(num: T) -> <BaseN<16> as AsciiConversion<T>>::Output
+    /// where
+    ///     BaseN<16>: AsciiConversion<T>,
+    /// {
+    ///     num.to_ascii::<16>()
+    /// }
+    ///
+    /// assert_eq!(hex(0xBEEFu16).as_str(), "BEEF");
+    /// assert_eq!(&*hex(-1i8), "-1");
+    /// assert_eq!(255u8.to_ascii::<2>().as_str(), "11111111");
+    /// ```
+    fn to_ascii<const BASE: usize>(self) -> <BaseN<BASE> as AsciiConversion<Self>>::Output
+    where
+        Self: Sized,
+        BaseN<BASE>: AsciiConversion<Self>,
+    {
+        <BaseN<BASE> as AsciiConversion<Self>>::convert(self)
+    }
+}
+
+// The number of bytes needed to write any integer between `min` and `max` in the given base.
+const fn integer_space(base: u32, min: i128, max: u128) -> usize {
+    if min < 0 {
+        required_space(base as u128, min.unsigned_abs(), true)
+    } else {
+        required_space(base as u128, max, false)
+    }
 }
 
 macro_rules! impl_numtoa_trait {
     (
         $type_name:ty,
         $core_function_name:ident,
-        $str_function_name:ident
+        $str_function_name:ident,
+        $radix_function_name:ident,
+        $radix_str_function_name:ident,
+        $alphabet_function_name:ident,
+        $alphabet_str_function_name:ident,
+        $try_function_name:ident,
+        $try_str_function_name:ident,
+        $left_function_name:ident,
+        $uninit_function_name:ident,
+        $uninit_str_function_name:ident,
+        $unchecked_function_name:ident,
+        $unchecked_str_function_name:ident,
+        $digit_count_function_name:ident
     ) => {
         impl NumToA for $type_name {
             fn numtoa(self, base: $type_name, string: &mut [u8]) -> &[u8] {
@@ -50,22 +308,434 @@ macro_rules! impl_numtoa_trait {
             fn numtoa_str(self, base: $type_name, buf: &mut [u8]) -> &str {
                 $str_function_name(self, base, buf)
             }
+
+            fn numtoa_radix(self, radix: Radix, string: &mut [u8]) -> &[u8] {
+                $radix_function_name(self, radix, string)
+            }
+
+            fn numtoa_radix_str(self, radix: Radix, buf: &mut [u8]) -> &str {
+                $radix_str_function_name(self, radix, buf)
+            }
+
+            fn numtoa_with_alphabet<'a>(
+                self,
+                radix: Radix,
+                alphabet: &Alphabet,
+                string: &'a mut [u8],
+            ) -> &'a [u8] {
+                $alphabet_function_name(self, radix, alphabet, string)
+            }
+
+            fn numtoa_with_alphabet_str<'a>(
+                self,
+                radix: Radix,
+                alphabet: &Alphabet,
+                buf: &'a mut [u8],
+            ) -> &'a str {
+                $alphabet_str_function_name(self, radix, alphabet, buf)
+            }
+
+            fn try_numtoa(self, base: $type_name, string: &mut [u8]) -> Result<&[u8], NumToAError> {
+                $try_function_name(self, base, string)
+            }
+
+            fn try_numtoa_str(self, base: $type_name, buf: &mut [u8]) -> Result<&str, NumToAError> {
+                $try_str_function_name(self, base, buf)
+            }
+
+            fn write_left(self, base: $type_name, string: &mut [u8]) -> usize {
+                $left_function_name(self, base, string)
+            }
+
+            fn numtoa_uninit(self, base: $type_name, string: &mut [MaybeUninit<u8>]) -> &[u8] {
+                $uninit_function_name(self, base, string)
+            }
+
+            fn numtoa_uninit_str(self, base: $type_name, buf: &mut [MaybeUninit<u8>]) -> &str {
+                $uninit_str_function_name(self, base, buf)
+            }
+
+            unsafe fn numtoa_unchecked(self, base: $type_name, string: &mut [u8]) -> &[u8] {
+                $unchecked_function_name(self, base, string)
+            }
+
+            unsafe fn numtoa_unchecked_str(self, base: $type_name, buf: &mut [u8]) -> &str {
+                $unchecked_str_function_name(self, base, buf)
+            }
+
+            fn digit_count(self, radix: Radix) -> usize {
+                $digit_count_function_name(self, radix)
+            }
+
+            fn numtoa_array<const BASE: u32, const N: usize>(self, string: &mut [u8; N]) -> &[u8] {
+                const {
+                    assert!(BASE >= 2 && BASE <= 36, "unsupported base");
+                    assert!(
+                        N >= integer_space(
+                            BASE,
+                            <$type_name>::MIN as i128,
+                            <$type_name>::MAX as u128
+                        ),
+                        "buffer too small"
+                    );
+                }
+                $radix_function_name(self, Radix::new(BASE), string)
+            }
+
+            fn numtoa_array_str<const BASE: u32, const N: usize>(self, buf: &mut [u8; N]) -> &str {
+                unsafe { core::str::from_utf8_unchecked(self.numtoa_array::<BASE, N>(buf)) }
+            }
         }
     };
 }
 
-impl_numtoa_trait!(i8, numtoa_i8, numtoa_i8_str);
-impl_numtoa_trait!(i16, numtoa_i16, numtoa_i16_str);
-impl_numtoa_trait!(i32, numtoa_i32, numtoa_i32_str);
-impl_numtoa_trait!(i64, numtoa_i64, numtoa_i64_str);
-impl_numtoa_trait!(i128, numtoa_i128, numtoa_i128_str);
-impl_numtoa_trait!(isize, numtoa_isize, numtoa_isize_str);
-impl_numtoa_trait!(u8, numtoa_u8, numtoa_u8_str);
-impl_numtoa_trait!(u16, numtoa_u16, numtoa_u16_str);
-impl_numtoa_trait!(u32, numtoa_u32, numtoa_u32_str);
-impl_numtoa_trait!(u64, numtoa_u64, numtoa_u64_str);
-impl_numtoa_trait!(u128, numtoa_u128, numtoa_u128_str);
-impl_numtoa_trait!(usize, numtoa_usize, numtoa_usize_str);
+macro_rules! impl_float_numtoa_trait {
+    (
+        $type_name:ty,
+        $required_space:ident,
+        $core_function_name:ident,
+        $str_function_name:ident,
+        $alphabet_function_name:ident,
+        $alphabet_str_function_name:ident,
+        $try_function_name:ident,
+        $try_str_function_name:ident,
+        $left_function_name:ident,
+        $uninit_function_name:ident,
+        $uninit_str_function_name:ident,
+        $digit_count_function_name:ident
+    ) => {
+        impl NumToA for $type_name {
+            fn numtoa(self, base: $type_name, string: &mut [u8]) -> &[u8] {
+                assert!(base == 10.0, "unsupported base");
+                $core_function_name(self, string)
+            }
+
+            fn numtoa_str(self, base: $type_name, buf: &mut [u8]) -> &str {
+                assert!(base == 10.0, "unsupported base");
+                $str_function_name(self, buf)
+            }
+
+            fn numtoa_radix(self, radix: Radix, string: &mut [u8]) -> &[u8] {
+                assert!(radix == Radix::DEC, "unsupported base");
+                $core_function_name(self, string)
+            }
+
+            fn numtoa_radix_str(self, radix: Radix, buf: &mut [u8]) -> &str {
+                assert!(radix == Radix::DEC, "unsupported base");
+                $str_function_name(self, buf)
+            }
+
+            fn numtoa_with_alphabet<'a>(
+                self,
+                radix: Radix,
+                alphabet: &Alphabet,
+                string: &'a mut [u8],
+            ) -> &'a [u8] {
+                assert!(radix == Radix::DEC, "unsupported base");
+                $alphabet_function_name(self, alphabet, string)
+            }
+
+            fn numtoa_with_alphabet_str<'a>(
+                self,
+                radix: Radix,
+                alphabet: &Alphabet,
+                buf: &'a mut [u8],
+            ) -> &'a str {
+                assert!(radix == Radix::DEC, "unsupported base");
+                $alphabet_str_function_name(self, alphabet, buf)
+            }
+
+            fn try_numtoa(self, base: $type_name, string: &mut [u8]) -> Result<&[u8], NumToAError> {
+                if base != 10.0 {
+                    return Err(NumToAError::UnsupportedBase);
+                }
+                $try_function_name(self, string)
+            }
+
+            fn try_numtoa_str(self, base: $type_name, buf: &mut [u8]) -> Result<&str, NumToAError> {
+                if base != 10.0 {
+                    return Err(NumToAError::UnsupportedBase);
+                }
+                $try_str_function_name(self, buf)
+            }
+
+            fn write_left(self, base: $type_name, string: &mut [u8]) -> usize {
+                assert!(base == 10.0, "unsupported base");
+                $left_function_name(self, string)
+            }
+
+            fn numtoa_uninit(self, base: $type_name, string: &mut [MaybeUninit<u8>]) -> &[u8] {
+                assert!(base == 10.0, "unsupported base");
+                $uninit_function_name(self, string)
+            }
+
+            fn numtoa_uninit_str(self, base: $type_name, buf: &mut [MaybeUninit<u8>]) -> &str {
+                assert!(base == 10.0, "unsupported base");
+                $uninit_str_function_name(self, buf)
+            }
+
+            unsafe fn numtoa_unchecked(self, base: $type_name, string: &mut [u8]) -> &[u8] {
+                self.numtoa(base, string)
+            }
+
+            unsafe fn numtoa_unchecked_str(self, base: $type_name, buf: &mut [u8]) -> &str {
+                self.numtoa_str(base, buf)
+            }
+
+            fn digit_count(self, radix: Radix) -> usize {
+                assert!(radix == Radix::DEC, "unsupported base");
+                $digit_count_function_name(self)
+            }
+
+            fn numtoa_array<const BASE: u32, const N: usize>(self, string: &mut [u8; N]) -> &[u8] {
+                const {
+                    assert!(BASE == 10, "unsupported base");
+                    assert!(N >= $required_space, "buffer too small");
+                }
+                $core_function_name(self, string)
+            }
+
+            fn numtoa_array_str<const BASE: u32, const N: usize>(self, buf: &mut [u8; N]) -> &str {
+                unsafe { core::str::from_utf8_unchecked(self.numtoa_array::<BASE, N>(buf)) }
+            }
+        }
+    };
+}
+
+impl_numtoa_trait!(
+    i8,
+    numtoa_i8,
+    numtoa_i8_str,
+    numtoa_i8_radix,
+    numtoa_i8_radix_str,
+    numtoa_i8_with_alphabet,
+    numtoa_i8_with_alphabet_str,
+    try_numtoa_i8,
+    try_numtoa_i8_str,
+    numtoa_i8_left,
+    numtoa_i8_uninit,
+    numtoa_i8_uninit_str,
+    numtoa_i8_unchecked,
+    numtoa_i8_unchecked_str,
+    digit_count_i8
+);
+impl_numtoa_trait!(
+    i16,
+    numtoa_i16,
+    numtoa_i16_str,
+    numtoa_i16_radix,
+    numtoa_i16_radix_str,
+    numtoa_i16_with_alphabet,
+    numtoa_i16_with_alphabet_str,
+    try_numtoa_i16,
+    try_numtoa_i16_str,
+    numtoa_i16_left,
+    numtoa_i16_uninit,
+    numtoa_i16_uninit_str,
+    numtoa_i16_unchecked,
+    numtoa_i16_unchecked_str,
+    digit_count_i16
+);
+impl_numtoa_trait!(
+    i32,
+    numtoa_i32,
+    numtoa_i32_str,
+    numtoa_i32_radix,
+    numtoa_i32_radix_str,
+    numtoa_i32_with_alphabet,
+    numtoa_i32_with_alphabet_str,
+    try_numtoa_i32,
+    try_numtoa_i32_str,
+    numtoa_i32_left,
+    numtoa_i32_uninit,
+    numtoa_i32_uninit_str,
+    numtoa_i32_unchecked,
+    numtoa_i32_unchecked_str,
+    digit_count_i32
+);
+impl_numtoa_trait!(
+    i64,
+    numtoa_i64,
+    numtoa_i64_str,
+    numtoa_i64_radix,
+    numtoa_i64_radix_str,
+    numtoa_i64_with_alphabet,
+    numtoa_i64_with_alphabet_str,
+    try_numtoa_i64,
+    try_numtoa_i64_str,
+    numtoa_i64_left,
+    numtoa_i64_uninit,
+    numtoa_i64_uninit_str,
+    numtoa_i64_unchecked,
+    numtoa_i64_unchecked_str,
+    digit_count_i64
+);
+#[cfg(feature = "u128")]
+impl_numtoa_trait!(
+    i128,
+    numtoa_i128,
+    numtoa_i128_str,
+    numtoa_i128_radix,
+    numtoa_i128_radix_str,
+    numtoa_i128_with_alphabet,
+    numtoa_i128_with_alphabet_str,
+    try_numtoa_i128,
+    try_numtoa_i128_str,
+    numtoa_i128_left,
+    numtoa_i128_uninit,
+    numtoa_i128_uninit_str,
+    numtoa_i128_unchecked,
+    numtoa_i128_unchecked_str,
+    digit_count_i128
+);
+impl_numtoa_trait!(
+    isize,
+    numtoa_isize,
+    numtoa_isize_str,
+    numtoa_isize_radix,
+    numtoa_isize_radix_str,
+    numtoa_isize_with_alphabet,
+    numtoa_isize_with_alphabet_str,
+    try_numtoa_isize,
+    try_numtoa_isize_str,
+    numtoa_isize_left,
+    numtoa_isize_uninit,
+    numtoa_isize_uninit_str,
+    numtoa_isize_unchecked,
+    numtoa_isize_unchecked_str,
+    digit_count_isize
+);
+impl_numtoa_trait!(
+    u8,
+    numtoa_u8,
+    numtoa_u8_str,
+    numtoa_u8_radix,
+    numtoa_u8_radix_str,
+    numtoa_u8_with_alphabet,
+    numtoa_u8_with_alphabet_str,
+    try_numtoa_u8,
+    try_numtoa_u8_str,
+    numtoa_u8_left,
+    numtoa_u8_uninit,
+    numtoa_u8_uninit_str,
+    numtoa_u8_unchecked,
+    numtoa_u8_unchecked_str,
+    digit_count_u8
+);
+impl_numtoa_trait!(
+    u16,
+    numtoa_u16,
+    numtoa_u16_str,
+    numtoa_u16_radix,
+    numtoa_u16_radix_str,
+    numtoa_u16_with_alphabet,
+    numtoa_u16_with_alphabet_str,
+    try_numtoa_u16,
+    try_numtoa_u16_str,
+    numtoa_u16_left,
+    numtoa_u16_uninit,
+    numtoa_u16_uninit_str,
+    numtoa_u16_unchecked,
+    numtoa_u16_unchecked_str,
+    digit_count_u16
+);
+impl_numtoa_trait!(
+    u32,
+    numtoa_u32,
+    numtoa_u32_str,
+    numtoa_u32_radix,
+    numtoa_u32_radix_str,
+    numtoa_u32_with_alphabet,
+    numtoa_u32_with_alphabet_str,
+    try_numtoa_u32,
+    try_numtoa_u32_str,
+    numtoa_u32_left,
+    numtoa_u32_uninit,
+    numtoa_u32_uninit_str,
+    numtoa_u32_unchecked,
+    numtoa_u32_unchecked_str,
+    digit_count_u32
+);
+impl_numtoa_trait!(
+    u64,
+    numtoa_u64,
+    numtoa_u64_str,
+    numtoa_u64_radix,
+    numtoa_u64_radix_str,
+    numtoa_u64_with_alphabet,
+    numtoa_u64_with_alphabet_str,
+    try_numtoa_u64,
+    try_numtoa_u64_str,
+    numtoa_u64_left,
+    numtoa_u64_uninit,
+    numtoa_u64_uninit_str,
+    numtoa_u64_unchecked,
+    numtoa_u64_unchecked_str,
+    digit_count_u64
+);
+#[cfg(feature = "u128")]
+impl_numtoa_trait!(
+    u128,
+    numtoa_u128,
+    numtoa_u128_str,
+    numtoa_u128_radix,
+    numtoa_u128_radix_str,
+    numtoa_u128_with_alphabet,
+    numtoa_u128_with_alphabet_str,
+    try_numtoa_u128,
+    try_numtoa_u128_str,
+    numtoa_u128_left,
+    numtoa_u128_uninit,
+    numtoa_u128_uninit_str,
+    numtoa_u128_unchecked,
+    numtoa_u128_unchecked_str,
+    digit_count_u128
+);
+impl_numtoa_trait!(
+    usize,
+    numtoa_usize,
+    numtoa_usize_str,
+    numtoa_usize_radix,
+    numtoa_usize_radix_str,
+    numtoa_usize_with_alphabet,
+    numtoa_usize_with_alphabet_str,
+    try_numtoa_usize,
+    try_numtoa_usize_str,
+    numtoa_usize_left,
+    numtoa_usize_uninit,
+    numtoa_usize_uninit_str,
+    numtoa_usize_unchecked,
+    numtoa_usize_unchecked_str,
+    digit_count_usize
+);
+impl_float_numtoa_trait!(
+    f32,
+    REQUIRED_SPACE_F32,
+    numtoa_f32,
+    numtoa_f32_str,
+    numtoa_f32_with_alphabet,
+    numtoa_f32_with_alphabet_str,
+    try_numtoa_f32,
+    try_numtoa_f32_str,
+    numtoa_f32_left,
+    numtoa_f32_uninit,
+    numtoa_f32_uninit_str,
+    digit_count_f32
+);
+impl_float_numtoa_trait!(
+    f64,
+    REQUIRED_SPACE_F64,
+    numtoa_f64,
+    numtoa_f64_str,
+    numtoa_f64_with_alphabet,
+    numtoa_f64_with_alphabet_str,
+    try_numtoa_f64,
+    try_numtoa_f64_str,
+    numtoa_f64_left,
+    numtoa_f64_uninit,
+    numtoa_f64_uninit_str,
+    digit_count_f64
+);
 
 #[test]
 fn str_convenience_trait() {
@@ -217,6 +887,7 @@ fn base16_u8_all_trait() {
     }
 }
 
+#[cfg(feature = "u128")]
 #[test]
 #[should_panic]
 #[cfg(debug_assertions)]
@@ -225,12 +896,14 @@ fn base10_i128_array_too_small() {
     let _ = 0i128.numtoa(10, &mut buffer);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn base10_i128_array_just_right() {
     let mut buffer = [0u8; 40];
     let _ = 0i128.numtoa(10, &mut buffer);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 #[should_panic]
 #[cfg(debug_assertions)]
@@ -239,12 +912,14 @@ fn base10_u128_array_too_small() {
     let _ = 0u128.numtoa(10, &mut buffer);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn base10_u128_array_just_right() {
     let mut buffer = [0u8; 39];
     let _ = 0u128.numtoa(10, &mut buffer);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn base8_min_signed_number() {
     let mut buffer = [0u8; 50];
@@ -261,6 +936,7 @@ fn base8_min_signed_number() {
     );
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn base16_min_signed_number() {
     let mut buffer = [0u8; 40];
@@ -276,3 +952,209 @@ fn base16_min_signed_number() {
         b"-80000000000000000000000000000000"
     );
 }
+
+#[cfg(feature = "u128")]
+#[test]
+fn try_numtoa_trait() {
+    let mut buffer = [0u8; 4];
+    assert_eq!((-123i8).try_numtoa(10, &mut buffer), Ok(&b"-123"[..]));
+    assert_eq!(
+        (-1234i16).try_numtoa(10, &mut buffer),
+        Err(NumToAError::BufferTooSmall { needed: 5 })
+    );
+    assert_eq!(
+        0u64.try_numtoa(1, &mut buffer),
+        Err(NumToAError::UnsupportedBase)
+    );
+    assert_eq!(
+        0i64.try_numtoa(-10, &mut buffer),
+        Err(NumToAError::UnsupportedBase)
+    );
+    assert_eq!(
+        u128::MAX.try_numtoa_str(16, &mut [0u8; 32]),
+        Ok("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF")
+    );
+}
+
+#[test]
+fn try_numtoa_trait_accepts_exact_buffers() {
+    // Unlike `numtoa`, the fallible API only needs room for the value being converted.
+    assert_eq!(0u8.try_numtoa_str(10, &mut [0u8; 1]), Ok("0"));
+    assert_eq!(
+        i64::MIN.try_numtoa_str(10, &mut [0u8; 20]),
+        Ok("-9223372036854775808")
+    );
+    assert_eq!(
+        i64::MIN.try_numtoa_str(10, &mut [0u8; 19]),
+        Err(NumToAError::BufferTooSmall { needed: 20 })
+    );
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn radix_trait() {
+    let mut buffer = [0u8; 128];
+    assert_eq!(
+        u64::MAX.numtoa_radix_str(Radix::HEX, &mut buffer),
+        "FFFFFFFFFFFFFFFF"
+    );
+    assert_eq!((-8i8).numtoa_radix_str(Radix::OCT, &mut buffer), "-10");
+    assert_eq!(
+        i128::MIN.numtoa_radix_str(Radix::DEC, &mut buffer),
+        "-170141183460469231731687303715884105728"
+    );
+    assert_eq!(35usize.numtoa_radix_str(Radix::MAX, &mut buffer), "Z");
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn alphabet_trait() {
+    let mut buffer = [0u8; 128];
+    assert_eq!(
+        u64::MAX.numtoa_with_alphabet_str(Radix::HEX, &Alphabet::LOWER, &mut buffer),
+        "ffffffffffffffff"
+    );
+    assert_eq!(
+        (-35i8).numtoa_with_alphabet_str(Radix::MAX, &Alphabet::LOWER, &mut buffer),
+        "-z"
+    );
+    assert_eq!(
+        i128::MIN.numtoa_with_alphabet_str(Radix::DEC, &Alphabet::LOWER, &mut buffer),
+        "-170141183460469231731687303715884105728"
+    );
+}
+
+#[test]
+fn float_trait() {
+    let mut buffer = [0u8; REQUIRED_SPACE_F64];
+    assert_eq!(1.5f64.numtoa_str(10.0, &mut buffer), "1.5");
+    assert_eq!((-0.1f32).numtoa_radix_str(Radix::DEC, &mut buffer), "-0.1");
+    assert_eq!(f64::NEG_INFINITY.numtoa(10.0, &mut buffer), b"-inf");
+    assert_eq!(
+        250.0f64.numtoa_with_alphabet_str(Radix::DEC, &Alphabet::BASE58, &mut buffer),
+        "361.1"
+    );
+    assert_eq!(1e300f64.try_numtoa_str(10.0, &mut [0u8; 6]), Ok("1e300"));
+    assert_eq!(
+        1e300f64.try_numtoa(10.0, &mut [0u8; 4]),
+        Err(NumToAError::BufferTooSmall { needed: 5 })
+    );
+    assert_eq!(
+        1.0f32.try_numtoa(16.0, &mut buffer),
+        Err(NumToAError::UnsupportedBase)
+    );
+}
+
+#[test]
+#[should_panic]
+fn float_trait_unsupported_radix() {
+    let _ = 1.0f64.numtoa_radix(Radix::HEX, &mut [0u8; REQUIRED_SPACE_F64]);
+}
+
+#[test]
+fn write_left_trait() {
+    let mut buffer = [0u8; 64];
+    let mut length = 0;
+    length += 0xBEEFu16.write_left(16, &mut buffer[length..]);
+    length += (-1i8).write_left(2, &mut buffer[length..]);
+    length += 0.25f64.write_left(10.0, &mut buffer[length..]);
+    length += f32::NEG_INFINITY.write_left(10.0, &mut buffer[length..]);
+    assert_eq!(&buffer[..length], b"BEEF-10.25-inf");
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn digit_count_trait() {
+    let mut buffer = [0u8; 40];
+    assert_eq!(3, 255u8.digit_count(Radix::DEC));
+    assert_eq!(9, i32::MIN.digit_count(Radix::HEX));
+    assert_eq!(40, i128::MIN.digit_count(Radix::DEC));
+    for f in [
+        0.0,
+        -0.0,
+        1e-7,
+        -1.5e300,
+        f64::NAN,
+        f64::NEG_INFINITY,
+        123.456,
+    ] {
+        assert_eq!(f.numtoa(10.0, &mut buffer).len(), f.digit_count(Radix::DEC));
+    }
+    assert_eq!(
+        f32::MIN_POSITIVE.numtoa(10.0, &mut buffer).len(),
+        f32::MIN_POSITIVE.digit_count(Radix::DEC)
+    );
+}
+
+#[test]
+#[should_panic]
+fn float_digit_count_unsupported_radix() {
+    let _ = 1.0f64.digit_count(Radix::HEX);
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn uninit_trait() {
+    let mut buffer = [MaybeUninit::uninit(); 40];
+    assert_eq!(
+        u64::MAX.numtoa_uninit_str(16, &mut buffer),
+        "FFFFFFFFFFFFFFFF"
+    );
+    assert_eq!(
+        i128::MIN.numtoa_uninit_str(10, &mut buffer),
+        "-170141183460469231731687303715884105728"
+    );
+    assert_eq!((-0.5f32).numtoa_uninit_str(10.0, &mut buffer), "-0.5");
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn array_trait() {
+    let mut buffer = [0u8; 40];
+    assert_eq!(
+        i128::MIN.numtoa_array_str::<10, 40>(&mut buffer),
+        "-170141183460469231731687303715884105728"
+    );
+    assert_eq!(
+        (-128i8).numtoa_array_str::<2, 9>(&mut [0u8; 9]),
+        "-10000000"
+    );
+    assert_eq!(0xFFu8.numtoa_array::<16, 2>(&mut [0u8; 2]), b"FF");
+    assert_eq!(
+        f64::MIN_POSITIVE
+            .numtoa_array_str::<10, REQUIRED_SPACE_F64>(&mut [0u8; REQUIRED_SPACE_F64]),
+        "2.2250738585072014e-308"
+    );
+}
+
+// Converts any integer in the given base through the trait, as generic code would.
+#[cfg(test)]
+fn to_ascii_generic<T: NumToA, const BASE: usize>(
+    num: T,
+) -> <BaseN<BASE> as AsciiConversion<T>>::Output
+where
+    BaseN<BASE>: AsciiConversion<T>,
+{
+    num.to_ascii::<BASE>()
+}
+
+#[test]
+fn to_ascii_trait() {
+    assert_eq!(
+        to_ascii_generic::<_, 10>(u64::MAX),
+        BaseN::<10>::u64(u64::MAX)
+    );
+    assert_eq!(&*to_ascii_generic::<_, 16>(-0x80i8), "-80");
+    assert_eq!(to_ascii_generic::<_, 36>(35usize).as_str(), "Z");
+    assert_eq!((-5i32).to_ascii::<2>().as_str(), "-101");
+    assert_eq!(u16::MAX.to_ascii::<58>().as_str(), "LUv");
+    assert_eq!(
+        core::mem::size_of_val(&0u32.to_ascii::<16>()),
+        core::mem::size_of::<crate::AsciiNumber<{ BaseN::<16>::REQUIRED_SPACE_U32 }>>()
+    );
+    #[cfg(feature = "u128")]
+    assert_eq!(
+        i128::MIN.to_ascii::<10>().as_str(),
+        "-170141183460469231731687303715884105728"
+    );
+}
@@ -0,0 +1,375 @@
+// An implementation of the Ryu algorithm by Ulf Adams, which finds the shortest decimal representation of a binary
+// floating point number that still parses back to the same number.
+//
+// The 128-bit products are computed with 64-bit halves so that targets without fast 128-bit arithmetic are not
+// penalized.
+
+use crate::ryu_tables::*;
+
+const DOUBLE_MANTISSA_BITS: u32 = 52;
+const DOUBLE_EXPONENT_BITS: u32 = 11;
+const DOUBLE_BIAS: i32 = 1023;
+const DOUBLE_POW5_INV_BITCOUNT: i32 = 125;
+const DOUBLE_POW5_BITCOUNT: i32 = 125;
+
+const FLOAT_MANTISSA_BITS: u32 = 23;
+const FLOAT_EXPONENT_BITS: u32 = 8;
+const FLOAT_BIAS: i32 = 127;
+const FLOAT_POW5_INV_BITCOUNT: i32 = 59;
+const FLOAT_POW5_BITCOUNT: i32 = 61;
+
+// A finite, non-zero float decoded into a decimal `mantissa * 10^exponent`, with the sign stored separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    pub(crate) mantissa: u64,
+    pub(crate) exponent: i32,
+}
+
+// The classification of a float, with finite non-zero values decoded into their shortest decimal form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Float {
+    Nan,
+    Infinite,
+    Zero,
+    Finite(Decimal),
+}
+
+// Returns `ceil(log2(5^e))` for `e` in `0..=3528`, or 1 when `e` is zero.
+const fn pow5bits(e: i32) -> i32 {
+    ((e as u32 * 1217359) >> 19) as i32 + 1
+}
+
+// Returns `floor(log10(2^e))` for `e` in `0..=1650`.
+const fn log10_pow2(e: i32) -> u32 {
+    (e as u32 * 78913) >> 18
+}
+
+// Returns `floor(log10(5^e))` for `e` in `0..=2620`.
+const fn log10_pow5(e: i32) -> u32 {
+    (e as u32 * 732923) >> 20
+}
+
+const fn pow5_factor(mut value: u64) -> u32 {
+    let mut count = 0;
+    while value.is_multiple_of(5) {
+        value /= 5;
+        count += 1;
+    }
+    count
+}
+
+const fn multiple_of_power_of_5(value: u64, p: u32) -> bool {
+    pow5_factor(value) >= p
+}
+
+// Multiplies two 64-bit integers, returning the low and high halves of the product.
+const fn umul128(a: u64, b: u64) -> (u64, u64) {
+    let a_lo = a & 0xFFFF_FFFF;
+    let a_hi = a >> 32;
+    let b_lo = b & 0xFFFF_FFFF;
+    let b_hi = b >> 32;
+
+    let b00 = a_lo * b_lo;
+    let b01 = a_lo * b_hi;
+    let b10 = a_hi * b_lo;
+    let b11 = a_hi * b_hi;
+
+    let mid1 = b10 + (b00 >> 32);
+    let mid2 = b01 + (mid1 & 0xFFFF_FFFF);
+
+    let hi = b11 + (mid1 >> 32) + (mid2 >> 32);
+    let lo = (mid2 << 32) | (b00 & 0xFFFF_FFFF);
+    (lo, hi)
+}
+
+// Shifts the 128-bit value `hi:lo` right by `dist`, which must be within 1 and 63.
+const fn shiftright128(lo: u64, hi: u64, dist: u32) -> u64 {
+    (hi << (64 - dist)) | (lo >> dist)
+}
+
+// Computes `(m * mul) >> j` for a 128-bit `mul` stored as `[low, high]`, where `j` is at least 65.
+const fn mul_shift_64(m: u64, mul: &[u64; 2], j: i32) -> u64 {
+    let (_, high1) = umul128(m, mul[0]);
+    let (low2, high2) = umul128(m, mul[1]);
+    let sum = low2.wrapping_add(high1);
+    let high = high2 + (sum < low2) as u64;
+    shiftright128(sum, high, (j - 64) as u32)
+}
+
+// Computes `(m * factor) >> shift` for a 64-bit `factor`, where `shift` is at least 33.
+const fn mul_shift_32(m: u32, factor: u64, shift: i32) -> u32 {
+    let bits0 = m as u64 * (factor & 0xFFFF_FFFF);
+    let bits1 = m as u64 * (factor >> 32);
+    let sum = (bits0 >> 32) + bits1;
+    (sum >> (shift - 32)) as u32
+}
+
+// Counts the number of decimal digits in `value`.
+pub(crate) const fn decimal_length(value: u64) -> usize {
+    let mut length = 1;
+    let mut power = 10u64;
+    while length < 20 && value >= power {
+        length += 1;
+        power = power.wrapping_mul(10);
+    }
+    length
+}
+
+// Classifies an `f64`, decoding finite non-zero values into their shortest round-trip decimal representation.
+pub(crate) const fn d2d(bits: u64) -> Float {
+    let ieee_mantissa = bits & ((1u64 << DOUBLE_MANTISSA_BITS) - 1);
+    let ieee_exponent =
+        ((bits >> DOUBLE_MANTISSA_BITS) & ((1u64 << DOUBLE_EXPONENT_BITS) - 1)) as u32;
+
+    if ieee_exponent == (1u32 << DOUBLE_EXPONENT_BITS) - 1 {
+        return if ieee_mantissa == 0 {
+            Float::Infinite
+        } else {
+            Float::Nan
+        };
+    } else if ieee_exponent == 0 && ieee_mantissa == 0 {
+        return Float::Zero;
+    }
+
+    let (e2, m2) = if ieee_exponent == 0 {
+        // Subnormal numbers have an implicit exponent of 1 and no implicit leading bit.
+        (
+            1 - DOUBLE_BIAS - DOUBLE_MANTISSA_BITS as i32 - 2,
+            ieee_mantissa,
+        )
+    } else {
+        (
+            ieee_exponent as i32 - DOUBLE_BIAS - DOUBLE_MANTISSA_BITS as i32 - 2,
+            (1u64 << DOUBLE_MANTISSA_BITS) | ieee_mantissa,
+        )
+    };
+    let even = m2 & 1 == 0;
+    let accept_bounds = even;
+
+    // Determine the interval of valid decimal representations.
+    let mv = 4 * m2;
+    // The lower boundary is closer when the mantissa is a power of two.
+    let mm_shift = (ieee_mantissa != 0 || ieee_exponent <= 1) as u64;
+
+    // Convert to a decimal power base using 128-bit arithmetic.
+    let mut vr;
+    let mut vp;
+    let mut vm;
+    let e10;
+    let mut vm_is_trailing_zeros = false;
+    if e2 >= 0 {
+        let q = log10_pow2(e2) - (e2 > 3) as u32;
+        e10 = q as i32;
+        let k = DOUBLE_POW5_INV_BITCOUNT + pow5bits(q as i32) - 1;
+        let i = -e2 + q as i32 + k;
+        let mul = &DOUBLE_POW5_INV_SPLIT[q as usize];
+        vr = mul_shift_64(4 * m2, mul, i);
+        vp = mul_shift_64(4 * m2 + 2, mul, i);
+        vm = mul_shift_64(4 * m2 - 1 - mm_shift, mul, i);
+        // Only one of mp, mv, and mm can be a multiple of 5, if any.
+        if q <= 21 && mv % 5 != 0 {
+            if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mv - 1 - mm_shift, q);
+            } else {
+                vp -= multiple_of_power_of_5(mv + 2, q) as u64;
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2) - (-e2 > 1) as u32;
+        e10 = q as i32 + e2;
+        let i = -e2 - q as i32;
+        let k = pow5bits(i) - DOUBLE_POW5_BITCOUNT;
+        let j = q as i32 - k;
+        let mul = &DOUBLE_POW5_SPLIT[i as usize];
+        vr = mul_shift_64(4 * m2, mul, j);
+        vp = mul_shift_64(4 * m2 + 2, mul, j);
+        vm = mul_shift_64(4 * m2 - 1 - mm_shift, mul, j);
+        if q <= 1 {
+            if accept_bounds {
+                // mm has one trailing zero bit exactly when mm_shift is 1.
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                // mp has one trailing zero bit, since it is 4 * m2 + 2.
+                vp -= 1;
+            }
+        }
+    }
+
+    // Find the shortest decimal representation in the interval of valid representations. Exact ties are
+    // rounded up rather than to even, matching the output of `core::fmt`.
+    let mut removed = 0i32;
+    let mut last_removed_digit = 0u64;
+    let output = if vm_is_trailing_zeros {
+        // The uncommon case, where the lower bound may be exactly representable with fewer digits.
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+        let round_up =
+            (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5;
+        vr + round_up as u64
+    } else {
+        // The common case, where only the last removed digit is needed to round.
+        let mut round_up = false;
+        if vp / 100 > vm / 100 {
+            round_up = vr % 100 >= 50;
+            vr /= 100;
+            vp /= 100;
+            vm /= 100;
+            removed += 2;
+        }
+        while vp / 10 > vm / 10 {
+            round_up = vr % 10 >= 5;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        vr + (vr == vm || round_up) as u64
+    };
+
+    Float::Finite(Decimal {
+        mantissa: output,
+        exponent: e10 + removed,
+    })
+}
+
+// Classifies an `f32`, decoding finite non-zero values into their shortest round-trip decimal representation.
+pub(crate) const fn f2d(bits: u32) -> Float {
+    let ieee_mantissa = bits & ((1u32 << FLOAT_MANTISSA_BITS) - 1);
+    let ieee_exponent = (bits >> FLOAT_MANTISSA_BITS) & ((1u32 << FLOAT_EXPONENT_BITS) - 1);
+
+    if ieee_exponent == (1u32 << FLOAT_EXPONENT_BITS) - 1 {
+        return if ieee_mantissa == 0 {
+            Float::Infinite
+        } else {
+            Float::Nan
+        };
+    } else if ieee_exponent == 0 && ieee_mantissa == 0 {
+        return Float::Zero;
+    }
+
+    let (e2, m2) = if ieee_exponent == 0 {
+        (
+            1 - FLOAT_BIAS - FLOAT_MANTISSA_BITS as i32 - 2,
+            ieee_mantissa,
+        )
+    } else {
+        (
+            ieee_exponent as i32 - FLOAT_BIAS - FLOAT_MANTISSA_BITS as i32 - 2,
+            (1u32 << FLOAT_MANTISSA_BITS) | ieee_mantissa,
+        )
+    };
+    let even = m2 & 1 == 0;
+    let accept_bounds = even;
+
+    // Determine the interval of valid decimal representations.
+    let mv = 4 * m2;
+    let mp = 4 * m2 + 2;
+    let mm_shift = (ieee_mantissa != 0 || ieee_exponent <= 1) as u32;
+    let mm = 4 * m2 - 1 - mm_shift;
+
+    // Convert to a decimal power base using 64-bit arithmetic.
+    let mut vr;
+    let mut vp;
+    let mut vm;
+    let e10;
+    let mut vm_is_trailing_zeros = false;
+    let mut last_removed_digit = 0u32;
+    if e2 >= 0 {
+        let q = log10_pow2(e2);
+        e10 = q as i32;
+        let k = FLOAT_POW5_INV_BITCOUNT + pow5bits(q as i32) - 1;
+        let i = -e2 + q as i32 + k;
+        let factor = FLOAT_POW5_INV_SPLIT[q as usize];
+        vr = mul_shift_32(mv, factor, i);
+        vp = mul_shift_32(mp, factor, i);
+        vm = mul_shift_32(mm, factor, i);
+        if q != 0 && (vp - 1) / 10 <= vm / 10 {
+            // One removed digit is needed even when the loop below does not run.
+            let l = FLOAT_POW5_INV_BITCOUNT + pow5bits(q as i32 - 1) - 1;
+            let factor = FLOAT_POW5_INV_SPLIT[q as usize - 1];
+            last_removed_digit = mul_shift_32(mv, factor, -e2 + q as i32 - 1 + l) % 10;
+        }
+        // Only one of mp, mv, and mm can be a multiple of 5, if any.
+        if q <= 9 && mv % 5 != 0 {
+            if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mm as u64, q);
+            } else {
+                vp -= multiple_of_power_of_5(mp as u64, q) as u32;
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2);
+        e10 = q as i32 + e2;
+        let i = -e2 - q as i32;
+        let k = pow5bits(i) - FLOAT_POW5_BITCOUNT;
+        let mut j = q as i32 - k;
+        let factor = FLOAT_POW5_SPLIT[i as usize];
+        vr = mul_shift_32(mv, factor, j);
+        vp = mul_shift_32(mp, factor, j);
+        vm = mul_shift_32(mm, factor, j);
+        if q != 0 && (vp - 1) / 10 <= vm / 10 {
+            j = q as i32 - 1 - (pow5bits(i + 1) - FLOAT_POW5_BITCOUNT);
+            last_removed_digit = mul_shift_32(mv, FLOAT_POW5_SPLIT[i as usize + 1], j) % 10;
+        }
+        if q <= 1 {
+            if accept_bounds {
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                vp -= 1;
+            }
+        }
+    }
+
+    // Find the shortest decimal representation in the interval of valid representations. Exact ties are
+    // rounded up rather than to even, matching the output of `core::fmt`.
+    let mut removed = 0i32;
+    let output = if vm_is_trailing_zeros {
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+        let round_up =
+            (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5;
+        vr + round_up as u32
+    } else {
+        while vp / 10 > vm / 10 {
+            last_removed_digit = vr % 10;
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+        vr + (vr == vm || last_removed_digit >= 5) as u32
+    };
+
+    Float::Finite(Decimal {
+        mantissa: output as u64,
+        exponent: e10 + removed,
+    })
+}